@@ -0,0 +1,97 @@
+use std::fs::read_to_string;
+use std::time::Instant;
+
+use clap::Parser;
+use paltoquet::stopwords::{Language, Stoplist};
+use paltoquet::tokenizers::WordTokenizer;
+use regex_automata::meta::Regex;
+use regex_syntax::escape as regex_escape;
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Path to a text file to tokenize, a synthetic one being used otherwise
+    #[arg(long)]
+    path: Option<String>,
+
+    /// Number of synthetic stopwords added to the bundled ones
+    #[arg(long, default_value_t = 5000)]
+    words: usize,
+
+    /// Number of times the tokens are filtered
+    #[arg(long, default_value_t = 10)]
+    rounds: usize,
+}
+
+// Compares the hash set stoplist to the single regex the tokenizer used to
+// compile its stopwords into.
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let mut words = Language::ALL
+        .iter()
+        .flat_map(|language| language.stopwords().iter().map(|word| word.to_string()))
+        .collect::<Vec<_>>();
+
+    words.extend((0..args.words).map(|i| format!("stopword{}", i)));
+
+    let text = match &args.path {
+        Some(path) => read_to_string(path)?,
+        None => "Le chat de la voisine mange la souris qu'il a vue dans le jardin. ".repeat(10_000),
+    };
+
+    let tokenizer = WordTokenizer::new();
+    let tokens = tokenizer
+        .tokenize(&text)
+        .map(|token| token.text)
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let regex = Regex::new(&format!(
+        "(?i)^(?:{})$",
+        words
+            .iter()
+            .map(|word| regex_escape(word))
+            .collect::<Vec<_>>()
+            .join("|")
+    ))?;
+    let regex_build_time = start.elapsed();
+
+    let start = Instant::now();
+    let stoplist = words.iter().collect::<Stoplist>();
+    let stoplist_build_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut regex_count = 0;
+    for _ in 0..args.rounds {
+        regex_count += tokens.iter().filter(|t| !regex.is_match(t)).count();
+    }
+    let regex_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut stoplist_count = 0;
+    for _ in 0..args.rounds {
+        stoplist_count += tokens.iter().filter(|t| !stoplist.contains(t)).count();
+    }
+    let stoplist_time = start.elapsed();
+
+    assert_eq!(regex_count, stoplist_count);
+
+    println!(
+        "{} stopwords, {} tokens x {} rounds",
+        words.len(),
+        tokens.len(),
+        args.rounds
+    );
+    println!(
+        "regex:    built in {:?}, filtered in {:?}",
+        regex_build_time, regex_time
+    );
+    println!(
+        "stoplist: built in {:?}, filtered in {:?} ({:.1}x faster)",
+        stoplist_build_time,
+        stoplist_time,
+        regex_time.as_secs_f64() / stoplist_time.as_secs_f64()
+    );
+
+    Ok(())
+}
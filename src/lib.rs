@@ -1,6 +1,7 @@
 pub mod keyers;
 pub mod phonetics;
 pub mod stemmers;
+pub mod stopwords;
 pub mod tokenizers;
 pub mod utils;
 
@@ -209,8 +209,8 @@ mod tests {
 
     #[test]
     fn test_double_consonant() {
-        assert_eq!(double_consonant("spell", None), true);
-        assert_eq!(double_consonant("spell", Some("l")), false);
+        assert!(double_consonant("spell", None));
+        assert!(!double_consonant("spell", Some("l")));
     }
 
     #[test]
@@ -1,5 +1,26 @@
+use std::borrow::Cow;
+
 pub mod en;
 pub mod fr;
 mod s_stemmer;
 
 pub use s_stemmer::s_stemmer;
+
+/// The stemmers of this module, so they can be selected at runtime, e.g. to
+/// normalize the tokens emitted by a tokenizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stemmer {
+    S,
+    Porter,
+    Carry,
+}
+
+impl Stemmer {
+    pub fn stem<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::S => s_stemmer(word),
+            Self::Porter => Cow::Owned(en::porter_stemmer(word)),
+            Self::Carry => Cow::Owned(fr::carry_stemmer(word)),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Reference:
+// https://snowballstem.org/algorithms/french/stop.txt
+#[rustfmt::skip]
+pub static STOPWORDS: &[&str] = &[
+    "a",
+    "à",
+    "ai",
+    "aie",
+    "aient",
+    "aies",
+    "ait",
+    "as",
+    "au",
+    "aura",
+    "aurai",
+    "auraient",
+    "aurais",
+    "aurait",
+    "auras",
+    "aurez",
+    "auriez",
+    "aurions",
+    "aurons",
+    "auront",
+    "aux",
+    "avaient",
+    "avais",
+    "avait",
+    "avec",
+    "avez",
+    "aviez",
+    "avions",
+    "avons",
+    "ayant",
+    "ayante",
+    "ayantes",
+    "ayants",
+    "ayez",
+    "ayons",
+    "c",
+    "c'",
+    "ce",
+    "ces",
+    "d",
+    "d'",
+    "dans",
+    "de",
+    "des",
+    "du",
+    "elle",
+    "en",
+    "es",
+    "est",
+    "et",
+    "étaient",
+    "étais",
+    "était",
+    "étant",
+    "étante",
+    "étantes",
+    "étants",
+    "été",
+    "étée",
+    "étées",
+    "étés",
+    "êtes",
+    "étiez",
+    "étions",
+    "eu",
+    "eue",
+    "eues",
+    "eûmes",
+    "eurent",
+    "eus",
+    "eusse",
+    "eussent",
+    "eusses",
+    "eussiez",
+    "eussions",
+    "eut",
+    "eût",
+    "eûtes",
+    "eux",
+    "fûmes",
+    "furent",
+    "fus",
+    "fusse",
+    "fussent",
+    "fusses",
+    "fussiez",
+    "fussions",
+    "fut",
+    "fût",
+    "fûtes",
+    "il",
+    "ils",
+    "j",
+    "j'",
+    "je",
+    "l",
+    "l'",
+    "la",
+    "le",
+    "les",
+    "leur",
+    "lui",
+    "m",
+    "m'",
+    "ma",
+    "mais",
+    "me",
+    "même",
+    "mes",
+    "moi",
+    "mon",
+    "n",
+    "n'",
+    "ne",
+    "nos",
+    "notre",
+    "nous",
+    "on",
+    "ont",
+    "ou",
+    "par",
+    "pas",
+    "pour",
+    "qu",
+    "qu'",
+    "que",
+    "qui",
+    "s",
+    "s'",
+    "sa",
+    "se",
+    "sera",
+    "serai",
+    "seraient",
+    "serais",
+    "serait",
+    "seras",
+    "serez",
+    "seriez",
+    "serions",
+    "serons",
+    "seront",
+    "ses",
+    "soient",
+    "sois",
+    "soit",
+    "sommes",
+    "son",
+    "sont",
+    "soyez",
+    "soyons",
+    "suis",
+    "sur",
+    "t",
+    "t'",
+    "ta",
+    "te",
+    "tes",
+    "toi",
+    "ton",
+    "tu",
+    "un",
+    "une",
+    "vos",
+    "votre",
+    "vous",
+    "y",
+];
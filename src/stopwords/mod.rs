@@ -0,0 +1,258 @@
+// Bundled stopword lists, mostly adapted from the Snowball project, and
+// usable with the tokenizers or to filter any other stream of words.
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::str::FromStr;
+
+mod de;
+mod en;
+mod es;
+mod fr;
+mod it;
+mod nl;
+mod pt;
+
+/// Version of the bundled lists, bumped every time one of them changes so
+/// that results can be reproduced.
+pub const VERSION: &str = "1.1.0";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Dutch,
+    English,
+    French,
+    German,
+    Italian,
+    Portuguese,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 7] = [
+        Self::Dutch,
+        Self::English,
+        Self::French,
+        Self::German,
+        Self::Italian,
+        Self::Portuguese,
+        Self::Spanish,
+    ];
+
+    /// ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Dutch => "nl",
+            Self::English => "en",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Italian => "it",
+            Self::Portuguese => "pt",
+            Self::Spanish => "es",
+        }
+    }
+
+    /// Bundled stopwords of the language, in lowercase.
+    pub fn stopwords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Dutch => nl::STOPWORDS,
+            Self::English => en::STOPWORDS,
+            Self::French => fr::STOPWORDS,
+            Self::German => de::STOPWORDS,
+            Self::Italian => it::STOPWORDS,
+            Self::Portuguese => pt::STOPWORDS,
+            Self::Spanish => es::STOPWORDS,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unsupported stopwords language {}", s))
+    }
+}
+
+fn fold(word: &str) -> Cow<'_, str> {
+    // NOTE: fast path for the overwhelmingly common case
+    if word.is_ascii() {
+        if word.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Cow::Owned(word.to_ascii_lowercase());
+        }
+
+        return Cow::Borrowed(word);
+    }
+
+    if word.chars().any(|c| c.is_uppercase() || c == '’') {
+        Cow::Owned(word.to_lowercase().replace('’', "'"))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+// FNV-1a, which is way faster than the default hasher on short keys such as
+// words, and whose lack of DoS resistance does not matter here.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A set of stopwords, matched regardless of case and of the kind of
+/// apostrophe used, e.g. "L’" will match "l'".
+#[derive(Debug, Clone, Default)]
+pub struct Stoplist {
+    words: HashSet<String, BuildHasherDefault<FnvHasher>>,
+    max_len: usize,
+}
+
+impl Stoplist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_language(language: Language) -> Self {
+        let mut stoplist = Self::new();
+        stoplist.add_language(language);
+        stoplist
+    }
+
+    pub fn add_language(&mut self, language: Language) {
+        self.extend(language.stopwords().iter().copied());
+    }
+
+    pub fn insert<T: AsRef<str>>(&mut self, word: T) {
+        let word = word.as_ref();
+
+        if !word.is_empty() {
+            let word = fold(word).into_owned();
+            self.max_len = self.max_len.max(word.len());
+            self.words.insert(word);
+        }
+    }
+
+    pub fn remove<T: AsRef<str>>(&mut self, word: T) {
+        self.words.remove(fold(word.as_ref()).as_ref());
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        // NOTE: folding can divide the byte length of a char by 3 at most,
+        // e.g. "’" -> "'"
+        if word.len() > self.max_len * 3 {
+            return false;
+        }
+
+        self.words.contains(fold(word).as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(|word| word.as_str())
+    }
+}
+
+impl<T: AsRef<str>> Extend<T> for Stoplist {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word);
+        }
+    }
+}
+
+impl<T: AsRef<str>> FromIterator<T> for Stoplist {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stoplist = Self::new();
+        stoplist.extend(iter);
+        stoplist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_languages() {
+        assert_eq!("fr".parse::<Language>(), Ok(Language::French));
+        assert_eq!("EN".parse::<Language>(), Ok(Language::English));
+        assert!("xx".parse::<Language>().is_err());
+
+        for language in Language::ALL {
+            let words = language.stopwords();
+
+            assert_eq!(language.code().parse::<Language>(), Ok(language));
+            assert_eq!(
+                words.iter().collect::<HashSet<_>>().len(),
+                words.len(),
+                "duplicate stopwords in {:?}",
+                language
+            );
+            assert!(words.iter().all(|word| fold(word) == *word));
+        }
+    }
+
+    #[test]
+    fn test_stoplist() {
+        let mut stoplist = Stoplist::from_language(Language::French);
+        stoplist.extend(["Cependant", "toutefois"]);
+        stoplist.remove("pas");
+
+        assert!(stoplist.contains("le"));
+        assert!(stoplist.contains("Le"));
+        assert!(stoplist.contains("L’"));
+        assert!(stoplist.contains("cependant"));
+        assert!(stoplist.contains("TOUTEFOIS"));
+        assert!(!stoplist.contains("pas"));
+        assert!(!stoplist.contains("chat"));
+        assert!(!stoplist.contains("the"));
+
+        stoplist.add_language(Language::English);
+
+        assert!(stoplist.contains("The"));
+        assert!(stoplist.contains("don’t"));
+
+        let stoplist = Stoplist::from_language(Language::Spanish);
+
+        assert!(stoplist.contains("Estuviésemos"));
+        assert!(stoplist.contains("tened"));
+        assert_eq!(stoplist.len(), 308);
+        assert_eq!(Stoplist::from_language(Language::Portuguese).len(), 203);
+
+        let stoplist: Stoplist = ["chat", ""].into_iter().collect();
+
+        assert_eq!(stoplist.len(), 1);
+        assert_eq!(
+            ["le", "chat", "dort"]
+                .into_iter()
+                .filter(|word| !stoplist.contains(word))
+                .collect::<Vec<_>>(),
+            vec!["le", "dort"]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+// Helpers dealing with Arabic & Hebrew words, whose diacritics are optional
+// and which often start with proclitics, e.g. "وبالكتاب" meaning "and with
+// the book".
+//
+// Proclitics cannot be told apart from a word's first letters without a
+// proper lexicon, e.g. "ب" in "بيت", so we only split them when they precede
+// the definite article, except for the Hebrew conjunction "ו" which very
+// rarely starts a word otherwise.
+//
+// NOTE: the Hebrew prepositions "ב", "ל" & "כ" absorb the article without
+// leaving any trace in unvocalized text, e.g. "בבית" for "ב" + "הבית", so
+// they are never split. Likewise, verbs starting with "ה", e.g. "הסביר" or
+// "התחיל", cannot always be told apart from nouns following the article, so
+// the most common verb patterns are left whole.
+
+#[inline]
+fn is_arabic(c: char) -> bool {
+    matches!(c, '\u{600}'..='\u{6ff}' | '\u{750}'..='\u{77f}' | '\u{8a0}'..='\u{8ff}')
+}
+
+#[inline]
+fn is_hebrew(c: char) -> bool {
+    matches!(c, '\u{591}'..='\u{5f4}' | '\u{fb1d}'..='\u{fb4f}')
+}
+
+// Harakat, quranic annotations & tatweel for Arabic, niqqud & cantillation
+// marks for Hebrew.
+#[inline]
+fn is_diacritic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{610}'..='\u{61a}'
+            | '\u{640}'
+            | '\u{64b}'..='\u{65f}'
+            | '\u{670}'
+            | '\u{6d6}'..='\u{6dc}'
+            | '\u{6df}'..='\u{6e4}'
+            | '\u{6e7}'..='\u{6e8}'
+            | '\u{6ea}'..='\u{6ed}'
+            | '\u{591}'..='\u{5bd}'
+            | '\u{5bf}'
+            | '\u{5c1}'..='\u{5c2}'
+            | '\u{5c4}'..='\u{5c5}'
+            | '\u{5c7}'
+    )
+}
+
+pub(crate) fn is_semitic_word(word: &str) -> bool {
+    word.chars().any(|c| is_arabic(c) || is_hebrew(c))
+}
+
+/// Strips diacritics & tatweel from the given word, if it has any.
+pub(crate) fn strip_diacritics(word: &str) -> Option<String> {
+    if !word.chars().any(is_diacritic) {
+        return None;
+    }
+
+    Some(word.chars().filter(|c| !is_diacritic(*c)).collect())
+}
+
+// NOTE: those words start with a fossilized article
+static ARABIC_EXCEPTIONS: [&str; 11] = [
+    "الله",
+    "الذي",
+    "التي",
+    "الذين",
+    "اللذان",
+    "اللتان",
+    "اللذين",
+    "اللتين",
+    "اللاتي",
+    "اللواتي",
+    "اللائي",
+];
+
+static HEBREW_EXCEPTIONS: [&str; 10] = [
+    "הייתה",
+    "היתה",
+    "הייתי",
+    "היית",
+    "היינו",
+    "הייתם",
+    "הרבה",
+    "הגיע",
+    "הגיעה",
+    "הגיעו",
+];
+
+// NOTE: those nouns look like verbs once prefixed by the article
+static HEBREW_NOUNS: [&str; 2] = ["תחנה", "תפילה"];
+
+fn is_exception(exceptions: &[&str], letters: &[(usize, char)]) -> bool {
+    exceptions
+        .iter()
+        .any(|word| word.chars().eq(letters.iter().map(|(_, c)| *c)))
+}
+
+fn arabic_proclitic_boundaries(letters: &[(usize, char)]) -> Vec<usize> {
+    let mut i = 0;
+
+    // Conjunctions
+    if matches!(letters[i].1, 'و' | 'ف') {
+        i += 1;
+    }
+
+    // NOTE: "ل" followed by the article drops its alef, e.g. "للكتاب"
+    if let [(_, 'ل'), (_, 'ل'), ..] = letters[i..] {
+        return if letters.len() - (i + 2) >= 2 {
+            vec![i, i + 1, i + 2]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let mut j = i;
+
+    // Prepositions
+    if matches!(letters.get(j), Some((_, 'ب' | 'ك' | 'ل'))) {
+        j += 1;
+    }
+
+    if !matches!(letters.get(j..j + 2), Some([(_, 'ا'), (_, 'ل')])) {
+        return Vec::new();
+    }
+
+    if is_exception(&ARABIC_EXCEPTIONS, &letters[j..]) {
+        return vec![i, j];
+    }
+
+    if letters.len() - (j + 2) < 2 {
+        return Vec::new();
+    }
+
+    vec![i, j, j + 2]
+}
+
+// Returns whether the given word, starting with "ה", looks like a past form
+// of the hif'il, e.g. "הסביר", or of the hitpa'el, e.g. "התלבש", binyanim.
+// A "ו" among the root letters of the latter is a vowel, and hints at a noun,
+// e.g. "התפוח".
+fn is_hebrew_verb(letters: &[(usize, char)]) -> bool {
+    if is_exception(&HEBREW_NOUNS, &letters[1..]) {
+        return false;
+    }
+
+    let letters = match letters {
+        [.., (_, 'ה' | 'ו')] if letters.len() == 6 => &letters[..5],
+        _ => letters,
+    };
+
+    match letters {
+        [_, (_, 'ת'), root @ ..] if root.len() == 3 => !root.iter().any(|(_, c)| *c == 'ו'),
+        [_, _, _, (_, 'י'), _] => true,
+        _ => false,
+    }
+}
+
+fn hebrew_proclitic_boundaries(letters: &[(usize, char)]) -> Vec<usize> {
+    let mut i = 0;
+
+    // Conjunction
+    if letters[i].1 == 'ו' && letters.len() > 2 {
+        i += 1;
+    }
+
+    let mut letter_boundaries = vec![i];
+
+    // Relativizers & prepositions absorbing the article
+    let j = match letters[i..] {
+        [(_, 'כ'), (_, 'ש'), (_, 'ה'), ..] => i + 2,
+        [(_, 'ש' | 'מ'), (_, 'ה'), ..] => i + 1,
+        _ => i,
+    };
+
+    if letters.get(j).is_some_and(|(_, c)| *c == 'ה')
+        && letters.len() - (j + 1) >= 3
+        && !is_exception(&HEBREW_EXCEPTIONS, &letters[j..])
+        && !is_hebrew_verb(&letters[j..])
+    {
+        letter_boundaries.extend([j, j + 1]);
+    }
+
+    letter_boundaries
+}
+
+/// Returns the byte offsets where the given Arabic or Hebrew word should be
+/// split to separate its proclitics, e.g. "وبالكتاب" -> "و", "ب", "ال",
+/// "كتاب". Diacritics remain attached to the letter they follow.
+pub(crate) fn proclitic_boundaries(word: &str) -> Vec<usize> {
+    let letters = word
+        .char_indices()
+        .filter(|(_, c)| !is_diacritic(*c))
+        .collect::<Vec<_>>();
+
+    if letters.is_empty() {
+        return Vec::new();
+    }
+
+    let letter_boundaries = if is_arabic(letters[0].1) {
+        arabic_proclitic_boundaries(&letters)
+    } else if is_hebrew(letters[0].1) {
+        hebrew_proclitic_boundaries(&letters)
+    } else {
+        Vec::new()
+    };
+
+    let mut boundaries = letter_boundaries
+        .into_iter()
+        .filter(|i| *i > 0)
+        .map(|i| letters[i].0)
+        .collect::<Vec<_>>();
+
+    boundaries.dedup();
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(word: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut start = 0;
+
+        for boundary in proclitic_boundaries(word) {
+            parts.push(&word[start..boundary]);
+            start = boundary;
+        }
+
+        parts.push(&word[start..]);
+        parts
+    }
+
+    #[test]
+    fn test_strip_diacritics() {
+        assert_eq!(strip_diacritics("كتاب"), None);
+        assert_eq!(strip_diacritics("كِتَابٌ"), Some("كتاب".to_string()));
+        assert_eq!(strip_diacritics("كتـــاب"), Some("كتاب".to_string()));
+        assert_eq!(strip_diacritics("שָׁלוֹם"), Some("שלום".to_string()));
+        assert_eq!(strip_diacritics("paltoquet"), None);
+    }
+
+    #[test]
+    fn test_proclitic_boundaries() {
+        assert_eq!(split("الكتاب"), vec!["ال", "كتاب"]);
+        assert_eq!(split("والكتاب"), vec!["و", "ال", "كتاب"]);
+        assert_eq!(split("وبالكتاب"), vec!["و", "ب", "ال", "كتاب"]);
+        assert_eq!(split("فالمدرسة"), vec!["ف", "ال", "مدرسة"]);
+        assert_eq!(split("للطلاب"), vec!["ل", "ل", "طلاب"]);
+        assert_eq!(split("وَالْكِتَابُ"), vec!["وَ", "الْ", "كِتَابُ"]);
+        assert_eq!(split("بيت"), vec!["بيت"]);
+        assert_eq!(split("وزير"), vec!["وزير"]);
+        assert_eq!(split("الله"), vec!["الله"]);
+        assert_eq!(split("والله"), vec!["و", "الله"]);
+        assert_eq!(split("لله"), vec!["لله"]);
+        assert_eq!(split("والد"), vec!["والد"]);
+
+        assert_eq!(split("הספר"), vec!["ה", "ספר"]);
+        assert_eq!(split("והספר"), vec!["ו", "ה", "ספר"]);
+        assert_eq!(split("מהבית"), vec!["מ", "ה", "בית"]);
+        assert_eq!(split("כשהילדים"), vec!["כש", "ה", "ילדים"]);
+        assert_eq!(split("ואני"), vec!["ו", "אני"]);
+        assert_eq!(split("הוא"), vec!["הוא"]);
+        assert_eq!(split("שהייתה"), vec!["שהייתה"]);
+        assert_eq!(split("שלום"), vec!["שלום"]);
+        assert_eq!(split("התחיל"), vec!["התחיל"]);
+        assert_eq!(split("התחילו"), vec!["התחילו"]);
+        assert_eq!(split("הסביר"), vec!["הסביר"]);
+        assert_eq!(split("הסבירה"), vec!["הסבירה"]);
+        assert_eq!(split("והסביר"), vec!["ו", "הסביר"]);
+        assert_eq!(split("התלמיד"), vec!["ה", "תלמיד"]);
+        assert_eq!(split("התלבש"), vec!["התלבש"]);
+        assert_eq!(split("התלבשה"), vec!["התלבשה"]);
+        assert_eq!(split("התמונה"), vec!["ה", "תמונה"]);
+        assert_eq!(split("התשובה"), vec!["ה", "תשובה"]);
+        assert_eq!(split("התפוח"), vec!["ה", "תפוח"]);
+        assert_eq!(split("התחנה"), vec!["ה", "תחנה"]);
+        assert_eq!(split("והתפילה"), vec!["ו", "ה", "תפילה"]);
+        assert_eq!(split("הילדים"), vec!["ה", "ילדים"]);
+        assert_eq!(split("בבית"), vec!["בבית"]);
+        assert_eq!(split("לילדים"), vec!["לילדים"]);
+    }
+}
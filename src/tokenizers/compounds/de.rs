@@ -0,0 +1,119 @@
+// Frequent German words used as compound parts, in lowercase.
+pub static WORDS: &[&str] = &[
+    "amt",
+    "angst",
+    "apfel",
+    "arbeit",
+    "auto",
+    "bahn",
+    "ball",
+    "bank",
+    "baum",
+    "bildung",
+    "brief",
+    "buch",
+    "bund",
+    "bürger",
+    "büro",
+    "computer",
+    "dampf",
+    "donau",
+    "eisen",
+    "energie",
+    "fahrt",
+    "feuer",
+    "fleisch",
+    "flug",
+    "forschung",
+    "frau",
+    "frieden",
+    "fuß",
+    "garten",
+    "geber",
+    "geburt",
+    "geld",
+    "gericht",
+    "gesellschaft",
+    "gesetz",
+    "gesundheit",
+    "hafen",
+    "hand",
+    "haus",
+    "hof",
+    "hund",
+    "hütte",
+    "jahr",
+    "kampf",
+    "kapitän",
+    "kind",
+    "kirche",
+    "konto",
+    "kraft",
+    "krank",
+    "krieg",
+    "kuchen",
+    "land",
+    "leben",
+    "lehrer",
+    "licht",
+    "liebe",
+    "mann",
+    "meister",
+    "minister",
+    "ministerium",
+    "mittel",
+    "mond",
+    "mütze",
+    "nacht",
+    "nehmer",
+    "nummer",
+    "platz",
+    "polizei",
+    "präsident",
+    "programm",
+    "rat",
+    "recht",
+    "regierung",
+    "reise",
+    "rind",
+    "schiff",
+    "schlüssel",
+    "schokolade",
+    "schrift",
+    "schuh",
+    "schule",
+    "sonne",
+    "spiel",
+    "sprache",
+    "staat",
+    "stadt",
+    "straße",
+    "strom",
+    "student",
+    "tag",
+    "telefon",
+    "tisch",
+    "turm",
+    "tür",
+    "uhr",
+    "universität",
+    "unternehmen",
+    "verfassung",
+    "vertrag",
+    "versicherung",
+    "volk",
+    "wagen",
+    "wahl",
+    "wasser",
+    "wehr",
+    "welt",
+    "wende",
+    "werk",
+    "wirtschaft",
+    "wissenschaft",
+    "woche",
+    "wort",
+    "zeit",
+    "zentrum",
+    "zug",
+];
@@ -0,0 +1,316 @@
+// Dictionary-based splitting of closed compounds, e.g. German or Dutch ones.
+//
+// A compound is split into the path through the lattice of lexicon words,
+// optionally followed by a linking morpheme such as "-s-" or "-en-", having
+// the fewest parts, then the fewest linking morphemes. Words that are found
+// in the lexicon themselves are never split.
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::iter::once;
+
+use lazy_static::lazy_static;
+
+use crate::stopwords::Language;
+
+mod de;
+mod nl;
+
+lazy_static! {
+    static ref GERMAN_DECOMPOUNDER: Decompounder = Decompounder::for_language(Language::German);
+    static ref DUTCH_DECOMPOUNDER: Decompounder = Decompounder::for_language(Language::Dutch);
+}
+
+fn lowercase(text: &str) -> Cow<'_, str> {
+    if text.chars().any(|c| c.is_uppercase()) {
+        Cow::Owned(text.to_lowercase())
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+// Number of parts & number of linking morphemes of a split.
+type Cost = (usize, usize);
+
+/// Part of a compound word, as returned by [`Decompounder::split`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompoundPart<'a> {
+    /// Text of the part, including its linking morpheme if any, e.g. "Bundes"
+    pub text: &'a str,
+    /// Byte offset of the part in the compound
+    pub offset: usize,
+    /// Text of the part without its linking morpheme, e.g. "Bund"
+    pub stem: &'a str,
+}
+
+/// Splits closed compounds, e.g. "Bundesverfassungsgericht", into their parts
+/// using a lexicon and a list of linking morphemes.
+///
+/// [`Decompounder::for_language`] provides small bundled lexicons for German
+/// and Dutch, that can be extended with [`Decompounder::words`].
+#[derive(Debug, Clone)]
+pub struct Decompounder {
+    words: HashSet<String>,
+    max_char_count: usize,
+    min_part_char_count: usize,
+    linking_morphemes: Vec<String>,
+}
+
+impl Default for Decompounder {
+    fn default() -> Self {
+        Self {
+            words: HashSet::new(),
+            max_char_count: 0,
+            min_part_char_count: 3,
+            linking_morphemes: Vec::new(),
+        }
+    }
+}
+
+impl Decompounder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// NOTE: only German and Dutch come with a bundled lexicon.
+    pub fn for_language(language: Language) -> Self {
+        match language {
+            Language::German => Self::default()
+                .words(de::WORDS.iter().copied())
+                .linking_morphemes(["s", "es", "n", "en", "er", "e", "ens"]),
+            Language::Dutch => Self::default()
+                .words(nl::WORDS.iter().copied())
+                .linking_morphemes(["s", "e", "en", "er"]),
+            _ => Self::default(),
+        }
+    }
+
+    /// Add the given words to the lexicon.
+    pub fn words<I, T>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        for word in words {
+            let word = word.as_ref().to_lowercase();
+            self.max_char_count = self.max_char_count.max(word.chars().count());
+            self.words.insert(word);
+        }
+
+        self
+    }
+
+    /// Morphemes that may link a part to the next one, e.g. "s" in
+    /// "Arbeitsmarkt".
+    pub fn linking_morphemes<I, T>(mut self, morphemes: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        self.linking_morphemes = morphemes
+            .into_iter()
+            .map(|morpheme| morpheme.as_ref().to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Minimum number of chars of a part, without its linking morpheme.
+    pub fn min_part_char_count(mut self, count: usize) -> Self {
+        self.min_part_char_count = count;
+        self
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(lowercase(word).as_ref())
+    }
+
+    // Lengths of the linking morphemes, including the empty one, found at the
+    // beginning of the given text.
+    fn linking_morpheme_lens<'a>(&'a self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
+        once(0).chain(self.linking_morphemes.iter().filter_map(|morpheme| {
+            text.get(..morpheme.len())
+                .filter(|prefix| lowercase(prefix) == morpheme.as_str())
+                .map(|prefix| prefix.len())
+        }))
+    }
+
+    /// Returns the parts of the given compound, or `None` if it cannot be
+    /// fully covered by at least two lexicon words.
+    pub fn split<'a>(&self, word: &'a str) -> Option<Vec<CompoundPart<'a>>> {
+        if self.words.is_empty() || self.contains(word) {
+            return None;
+        }
+
+        let offsets = word
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(once(word.len()))
+            .collect::<Vec<_>>();
+
+        let n = offsets.len() - 1;
+
+        // NOTE: best[i] is the cost, i.e. (parts, linking morphemes), of the
+        // best split of the word up to its i-th char boundary, along with the
+        // previous boundary and the end of the last part's stem
+        let mut best: Vec<Option<(Cost, usize, usize)>> = vec![None; n + 1];
+        best[0] = Some(((0, 0), 0, 0));
+
+        for i in 0..n {
+            let ((parts, morphemes), _, _) = match best[i] {
+                Some(path) => path,
+                None => continue,
+            };
+
+            let max = (i + self.max_char_count).min(n);
+
+            for k in (i + self.min_part_char_count)..=max {
+                let stem_end = offsets[k];
+
+                if !self.contains(&word[offsets[i]..stem_end]) {
+                    continue;
+                }
+
+                for len in self.linking_morpheme_lens(&word[stem_end..]) {
+                    // NOTE: the last part cannot have a linking morpheme
+                    if len > 0 && stem_end + len == word.len() {
+                        continue;
+                    }
+
+                    let j = offsets.partition_point(|offset| *offset < stem_end + len);
+                    let cost = (parts + 1, morphemes + usize::from(len > 0));
+
+                    if best[j].map_or(true, |(best_cost, _, _)| cost < best_cost) {
+                        best[j] = Some((cost, i, stem_end));
+                    }
+                }
+            }
+        }
+
+        let ((part_count, _), _, _) = best[n]?;
+
+        if part_count < 2 {
+            return None;
+        }
+
+        let mut parts = Vec::with_capacity(part_count);
+        let mut j = n;
+
+        while j > 0 {
+            let (_, i, stem_end) = best[j].unwrap();
+
+            parts.push(CompoundPart {
+                text: &word[offsets[i]..offsets[j]],
+                offset: offsets[i],
+                stem: &word[offsets[i]..stem_end],
+            });
+
+            j = i;
+        }
+
+        parts.reverse();
+        Some(parts)
+    }
+}
+
+/// Splits the given compound using the bundled lexicon of the given language,
+/// e.g. "Bundesverfassungsgericht" -> "Bundes", "verfassungs", "gericht".
+pub fn split_compound(word: &str, language: Language) -> Option<Vec<CompoundPart<'_>>> {
+    match language {
+        Language::German => GERMAN_DECOMPOUNDER.split(word),
+        Language::Dutch => DUTCH_DECOMPOUNDER.split(word),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(word: &str, language: Language) -> Option<Vec<(&str, &str)>> {
+        split_compound(word, language).map(|parts| {
+            parts
+                .into_iter()
+                .map(|part| (part.text, part.stem))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_split_compound() {
+        assert_eq!(
+            split("Bundesverfassungsgericht", Language::German),
+            Some(vec![
+                ("Bundes", "Bund"),
+                ("verfassungs", "verfassung"),
+                ("gericht", "gericht")
+            ])
+        );
+        assert_eq!(
+            split("Donaudampfschifffahrtsgesellschaft", Language::German),
+            Some(vec![
+                ("Donau", "Donau"),
+                ("dampf", "dampf"),
+                ("schiff", "schiff"),
+                ("fahrts", "fahrt"),
+                ("gesellschaft", "gesellschaft")
+            ])
+        );
+        assert_eq!(
+            split("Krankenhaus", Language::German),
+            Some(vec![("Kranken", "Krank"), ("haus", "haus")])
+        );
+        assert_eq!(
+            split("Hundehütte", Language::German),
+            Some(vec![("Hunde", "Hund"), ("hütte", "hütte")])
+        );
+        assert_eq!(
+            split("ARBEITGEBER", Language::German),
+            Some(vec![("ARBEIT", "ARBEIT"), ("GEBER", "GEBER")])
+        );
+        assert_eq!(split("Verfassung", Language::German), None);
+        assert_eq!(split("Bundes", Language::German), None);
+        assert_eq!(split("Paltoquet", Language::German), None);
+
+        assert_eq!(
+            split("ziekenhuis", Language::Dutch),
+            Some(vec![("zieken", "ziek"), ("huis", "huis")])
+        );
+        assert_eq!(
+            split("staatssecretaris", Language::Dutch),
+            Some(vec![("staats", "staat"), ("secretaris", "secretaris")])
+        );
+        assert_eq!(
+            split("kinderopvang", Language::Dutch),
+            Some(vec![("kinder", "kind"), ("opvang", "opvang")])
+        );
+
+        assert_eq!(split("Krankenhaus", Language::French), None);
+    }
+
+    #[test]
+    fn test_decompounder() {
+        let decompounder = Decompounder::new()
+            .words(["Kaffee", "Tasse"])
+            .linking_morphemes(["n"]);
+
+        assert_eq!(
+            decompounder.split("Kaffeetasse"),
+            Some(vec![
+                CompoundPart {
+                    text: "Kaffee",
+                    offset: 0,
+                    stem: "Kaffee"
+                },
+                CompoundPart {
+                    text: "tasse",
+                    offset: 6,
+                    stem: "tasse"
+                }
+            ])
+        );
+        assert_eq!(decompounder.split("Kaffeetassen"), None);
+        assert_eq!(
+            decompounder.min_part_char_count(7).split("Kaffeetasse"),
+            None
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// Frequent Dutch words used as compound parts, in lowercase.
+pub static WORDS: &[&str] = &[
+    "ambt",
+    "appel",
+    "arbeid",
+    "bal",
+    "bank",
+    "bloem",
+    "boek",
+    "boom",
+    "bouw",
+    "brief",
+    "burger",
+    "centrum",
+    "dag",
+    "fiets",
+    "gemeente",
+    "gezondheid",
+    "haven",
+    "hok",
+    "hond",
+    "hoofd",
+    "huis",
+    "ijzer",
+    "jaar",
+    "kantoor",
+    "kerk",
+    "kind",
+    "kraan",
+    "land",
+    "leven",
+    "liefde",
+    "maatschappij",
+    "man",
+    "markt",
+    "meester",
+    "middel",
+    "minister",
+    "nacht",
+    "nummer",
+    "onderzoek",
+    "oorlog",
+    "opvang",
+    "pad",
+    "plaats",
+    "president",
+    "raad",
+    "regering",
+    "reis",
+    "rekening",
+    "schip",
+    "school",
+    "secretaris",
+    "spel",
+    "spoor",
+    "staat",
+    "stad",
+    "station",
+    "straat",
+    "strijd",
+    "taal",
+    "taart",
+    "tafel",
+    "telefoon",
+    "toren",
+    "trein",
+    "vaart",
+    "verdrag",
+    "verkiezing",
+    "verzekering",
+    "vlees",
+    "voet",
+    "vrede",
+    "vrouw",
+    "water",
+    "week",
+    "weg",
+    "wereld",
+    "wet",
+    "wetenschap",
+    "zee",
+    "ziek",
+    "zon",
+    "zorg",
+];
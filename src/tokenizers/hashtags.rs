@@ -26,7 +26,7 @@ impl<'a> TryFrom<&'a str> for HashtagParts<'a> {
 
         match chars.next() {
             None => return Err(()),
-            Some((_, c)) if c != '#' && c != '$' => return Err(()),
+            Some((_, c)) if c != '#' => return Err(()),
             _ => (),
         };
 
@@ -115,7 +115,7 @@ impl<'a> Iterator for HashtagParts<'a> {
     }
 }
 
-pub fn split_hashtag(text: &str) -> Option<HashtagParts> {
+pub fn split_hashtag(text: &str) -> Option<HashtagParts<'_>> {
     HashtagParts::try_from(text).ok()
 }
 
@@ -175,4 +175,9 @@ mod tests {
         );
         assert_eq!(split_hashtag("#final19"), vec!["final", "19"]);
     }
+
+    #[test]
+    fn test_cashtags_are_not_split() {
+        assert!(super::split_hashtag("$TSLA").is_none());
+    }
 }
@@ -0,0 +1,247 @@
+use super::words::starts_with_vowel;
+use crate::stopwords::Language;
+
+/// Rule of a [`JunkDetector`] having flagged a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JunkReason {
+    TooLong,
+    RepeatedChars,
+    TooManyConsecutiveConsonants,
+    TooManyConsecutiveVowels,
+    NoVowels,
+}
+
+impl JunkReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TooLong => "too-long",
+            Self::RepeatedChars => "repeated-chars",
+            Self::TooManyConsecutiveConsonants => "too-many-consecutive-consonants",
+            Self::TooManyConsecutiveVowels => "too-many-consecutive-vowels",
+            Self::NoVowels => "no-vowels",
+        }
+    }
+}
+
+/// Heuristics flagging tokens that are unlikely to be actual words.
+///
+/// By default, a token is considered as junk if:
+///   1. it is too long to be a plausible word (> 30 bytes)
+///   2. it has more than 3 consecutive identical chars
+///   3. it has more than 7 consecutive consonants
+///   4. it has more than 6 consecutive vowels
+///   5. it has no vowels (except stuff like "l'" or "qu'")
+///
+/// Every rule can be disabled or tuned, and [`JunkDetector::for_language`]
+/// provides sensible settings for some languages.
+#[derive(Debug, Clone)]
+pub struct JunkDetector {
+    max_len: Option<usize>,
+    max_repeated_chars: Option<usize>,
+    max_consecutive_consonants: Option<usize>,
+    max_consecutive_vowels: Option<usize>,
+    require_vowels: bool,
+    extra_vowels: String,
+}
+
+impl Default for JunkDetector {
+    fn default() -> Self {
+        Self {
+            max_len: Some(30),
+            max_repeated_chars: Some(3),
+            max_consecutive_consonants: Some(7),
+            max_consecutive_vowels: Some(6),
+            require_vowels: true,
+            extra_vowels: String::new(),
+        }
+    }
+}
+
+impl JunkDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn for_language(language: Language) -> Self {
+        match language {
+            // NOTE: compounds make for long words with large consonant
+            // clusters, e.g. "Angstschweißausbrüche"
+            Language::German => Self::default()
+                .max_len(Some(60))
+                .max_consecutive_consonants(Some(8))
+                .extra_vowels("öÖ"),
+            Language::Dutch => Self::default()
+                .max_len(Some(50))
+                .max_consecutive_consonants(Some(8)),
+            _ => Self::default(),
+        }
+    }
+
+    /// Maximum length of a token, in bytes.
+    pub fn max_len(mut self, max: Option<usize>) -> Self {
+        self.max_len = max;
+        self
+    }
+
+    pub fn max_repeated_chars(mut self, max: Option<usize>) -> Self {
+        self.max_repeated_chars = max;
+        self
+    }
+
+    pub fn max_consecutive_consonants(mut self, max: Option<usize>) -> Self {
+        self.max_consecutive_consonants = max;
+        self
+    }
+
+    pub fn max_consecutive_vowels(mut self, max: Option<usize>) -> Self {
+        self.max_consecutive_vowels = max;
+        self
+    }
+
+    pub fn require_vowels(mut self, required: bool) -> Self {
+        self.require_vowels = required;
+        self
+    }
+
+    /// Chars to consider as vowels on top of the latin ones, e.g. "ö".
+    pub fn extra_vowels<T: Into<String>>(mut self, vowels: T) -> Self {
+        self.extra_vowels = vowels.into();
+        self
+    }
+
+    fn exceeds(max: Option<usize>, count: usize) -> bool {
+        max.is_some_and(|max| count > max)
+    }
+
+    /// Returns the reason why the given token should be considered as junk,
+    /// if any.
+    pub fn check(&self, string: &str) -> Option<JunkReason> {
+        if Self::exceeds(self.max_len, string.len()) {
+            return Some(JunkReason::TooLong);
+        }
+
+        let mut total_vowel_count: usize = 0;
+        let mut consecutive_vowel_count: usize = 0;
+        let mut consecutive_consonant_count: usize = 0;
+        let mut has_punct = false;
+        let mut last_char_opt: Option<(char, usize)> = None;
+
+        for (i, c) in string.char_indices() {
+            match &mut last_char_opt {
+                Some((last_c, count)) if *last_c == c => *count += 1,
+                _ => last_char_opt = Some((c, 1)),
+            }
+
+            if Self::exceeds(self.max_repeated_chars, last_char_opt.unwrap().1) {
+                return Some(JunkReason::RepeatedChars);
+            }
+
+            if starts_with_vowel(&string[i..]) || self.extra_vowels.contains(c) {
+                consecutive_consonant_count = 0;
+                total_vowel_count += 1;
+                consecutive_vowel_count += 1;
+            } else if c.is_alphabetic() {
+                consecutive_vowel_count = 0;
+                consecutive_consonant_count += 1;
+            } else {
+                consecutive_consonant_count = 0;
+                consecutive_vowel_count = 0;
+                has_punct = true;
+            }
+
+            if Self::exceeds(self.max_consecutive_vowels, consecutive_vowel_count) {
+                return Some(JunkReason::TooManyConsecutiveVowels);
+            }
+
+            if Self::exceeds(self.max_consecutive_consonants, consecutive_consonant_count) {
+                return Some(JunkReason::TooManyConsecutiveConsonants);
+            }
+        }
+
+        if self.require_vowels && total_vowel_count == 0 && !has_punct {
+            return Some(JunkReason::NoVowels);
+        }
+
+        None
+    }
+
+    pub fn is_junk(&self, string: &str) -> bool {
+        self.check(string).is_some()
+    }
+}
+
+/// Checks whether the given token is junk using the default settings of
+/// [`JunkDetector`].
+pub fn is_junk(string: &str) -> bool {
+    JunkDetector::default().is_junk(string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_junk() {
+        let tests = [
+            ("aeaeaea", true),
+            ("aeaeae", false),
+            ("cbcbcbcb", true),
+            ("paltoquet", false),
+            ("azazazazazazazazazazazazazazazazazazaz", true),
+            ("d", true),
+            ("d'", false),
+            ("créé", false),
+            ("créée", false),
+            ("creee", false),
+            ("creeee", true),
+            ("TATA", false),
+            ("bookkeeper", false),
+            ("mississippi", false),
+        ];
+
+        for (string, expected) in tests {
+            assert_eq!(is_junk(string), expected, "{}", string);
+        }
+    }
+
+    #[test]
+    fn test_junk_detector() {
+        let detector = JunkDetector::new();
+
+        assert_eq!(detector.check("paltoquet"), None);
+        assert_eq!(
+            detector.check("azazazazazazazazazazazazazazazazazazaz"),
+            Some(JunkReason::TooLong)
+        );
+        assert_eq!(detector.check("creeee"), Some(JunkReason::RepeatedChars));
+        assert_eq!(
+            detector.check("cbcbcbcb"),
+            Some(JunkReason::TooManyConsecutiveConsonants)
+        );
+        assert_eq!(
+            detector.check("aeaeaea"),
+            Some(JunkReason::TooManyConsecutiveVowels)
+        );
+        assert_eq!(detector.check("d"), Some(JunkReason::NoVowels));
+
+        let detector = JunkDetector::new()
+            .max_repeated_chars(None)
+            .require_vowels(false);
+
+        assert_eq!(detector.check("creeee"), None);
+        assert_eq!(detector.check("d"), None);
+
+        let german = JunkDetector::for_language(Language::German);
+
+        assert_eq!(
+            JunkDetector::new().check("schön"),
+            Some(JunkReason::NoVowels)
+        );
+        assert_eq!(german.check("schön"), None);
+        assert_eq!(german.check("Angstschweißausbrüche"), None);
+        assert_eq!(
+            JunkDetector::new().check("Angstschweißausbrüche"),
+            Some(JunkReason::TooManyConsecutiveConsonants)
+        );
+    }
+}
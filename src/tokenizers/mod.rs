@@ -1,15 +1,24 @@
+mod clitics;
+mod compounds;
 mod fingerprint;
 mod hashtags;
+mod junk;
 mod ngrams;
 mod paragraphs;
+mod ptb;
+mod segmentation;
 mod sentences;
 mod words;
 
+pub use compounds::{split_compound, CompoundPart, Decompounder};
 pub use fingerprint::FingerprintTokenizer;
 pub use hashtags::split_hashtag;
+pub use junk::{is_junk, JunkDetector, JunkReason};
 pub use ngrams::{ngrams_len, ngrams_range_len, NgramsIteratorExt};
-pub use paragraphs::split_paragraphs;
-pub use sentences::split_sentences;
+pub use paragraphs::{split_paragraph_spans, split_paragraphs};
+pub use ptb::unescape_penn_treebank;
+pub use sentences::{split_sentence_spans, split_sentences, SentenceSpans, Sentences};
 pub use words::{
-    is_junk, WordToken, WordTokenKind, WordTokenizer, WordTokenizerBuilder, WordTokens,
+    expand_inclusive_form, CharOffsets, ElisionMode, Normalization, PatternPriority, Platform,
+    SemiticMode, WordToken, WordTokenKind, WordTokenizer, WordTokenizerBuilder, WordTokens,
 };
@@ -289,7 +289,7 @@ mod tests {
     use super::*;
     use crate::tokenizers::WordToken;
 
-    fn collect_ngrams<'a>(target: Vec<&'a str>, n: usize) -> Vec<Vec<&'a str>> {
+    fn collect_ngrams(target: Vec<&str>, n: usize) -> Vec<Vec<&str>> {
         target.into_iter().ngrams(n).collect()
     }
 
@@ -363,7 +363,7 @@ mod tests {
 
     #[test]
     fn test_ngrams_word_tokens() {
-        let sentence = vec![
+        let sentence = [
             WordToken::word("the"),
             WordToken::word("cat"),
             WordToken::word("eats"),
@@ -426,7 +426,7 @@ mod tests {
 
     #[test]
     fn test_less_tokens_than_n() {
-        let sentence = vec!["the", "cat"];
+        let sentence = ["the", "cat"];
 
         // Normal
         assert_eq!(
@@ -437,11 +437,11 @@ mod tests {
 
         // Range
         assert_eq!(
-            vec!["chat"].iter().ngrams_range(1..=2).collect::<Vec<_>>(),
+            ["chat"].iter().ngrams_range(1..=2).collect::<Vec<_>>(),
             vec![vec![&"chat"]]
         );
         assert_eq!(
-            vec!["chat"].iter().ngrams_range(1..=2).size_hint(),
+            ["chat"].iter().ngrams_range(1..=2).size_hint(),
             (1, Some(1))
         );
 
@@ -9,13 +9,22 @@ lazy_static! {
 }
 
 pub fn split_paragraphs(text: &str, aerated: bool) -> impl Iterator<Item = &str> {
+    split_paragraph_spans(text, aerated).map(|(_, _, paragraph)| paragraph)
+}
+
+/// Same as [`split_paragraphs`] but yielding `(start, end, text)` triples
+/// where `start` and `end` are byte offsets into the original text.
+pub fn split_paragraph_spans(
+    text: &str,
+    aerated: bool,
+) -> impl Iterator<Item = (usize, usize, &str)> {
     let splitted = if aerated {
         AERATED_PARAGRAPH_SPLITTER_REGEX.split(text)
     } else {
         PARAGRAPH_SPLITTER_REGEX.split(text)
     };
 
-    splitted.map(|span| &text[span.start..span.end])
+    splitted.map(|span| (span.start, span.end, &text[span.start..span.end]))
 }
 
 #[cfg(test)]
@@ -56,4 +65,24 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_paragraph_spans() {
+        let text = "First paragraph.\n\nSecond. Still second.\r\n  \r\nThird.";
+
+        let spans = split_paragraph_spans(text, true).collect::<Vec<_>>();
+
+        assert_eq!(
+            spans,
+            vec![
+                (0, 16, "First paragraph."),
+                (18, 39, "Second. Still second."),
+                (45, 51, "Third.")
+            ]
+        );
+
+        for (start, end, paragraph) in spans {
+            assert_eq!(&text[start..end], paragraph);
+        }
+    }
 }
@@ -0,0 +1,138 @@
+// Helpers implementing the Penn Treebank tokenization conventions, as expected
+// by most parsers & taggers trained on the PTB.
+//
+// References:
+// https://www.cis.upenn.edu/~treebank/tokenization.html
+// https://www.nltk.org/api/nltk.tokenize.treebank.html
+
+// NOTE: the split index is expressed in bytes
+static SPECIAL_WORDS: [(&str, usize); 8] = [
+    ("cannot", 3),
+    ("gimme", 3),
+    ("gonna", 3),
+    ("gotta", 3),
+    ("lemme", 3),
+    ("wanna", 3),
+    ("'tis", 2),
+    ("'twas", 2),
+];
+
+static ESCAPES: [(&str, &str); 11] = [
+    ("(", "-LRB-"),
+    (")", "-RRB-"),
+    ("[", "-LSB-"),
+    ("]", "-RSB-"),
+    ("{", "-LCB-"),
+    ("}", "-RCB-"),
+    ("“", "``"),
+    ("”", "''"),
+    ("‘", "`"),
+    ("’", "'"),
+    ("…", "..."),
+];
+
+/// Returns the PTB form of the given punctuation, e.g. "-LRB-" for "(", if it
+/// has one. Straight quotes being ambiguous, one must tell whether they open
+/// a quotation or not.
+pub(crate) fn escape_punctuation(punct: &str, opening: bool) -> Option<&'static str> {
+    match punct {
+        "\"" if opening => Some("``"),
+        "\"" => Some("''"),
+        "'" if opening => Some("`"),
+        "'" => None,
+        _ => ESCAPES
+            .iter()
+            .find(|(text, _)| *text == punct)
+            .map(|(_, escaped)| *escaped),
+    }
+}
+
+/// Returns whether a quote following the given char, if any, opens a
+/// quotation.
+pub(crate) fn is_opening_context(previous: Option<char>) -> bool {
+    match previous {
+        None => true,
+        Some(c) => c.is_whitespace() || matches!(c, '(' | '[' | '{' | '<'),
+    }
+}
+
+/// Returns where the given word should be split according to the PTB, e.g.
+/// "do" + "n't" for "don't" or "can" + "not" for "cannot".
+pub(crate) fn split_contraction(word: &str) -> Option<usize> {
+    for suffix in ["n't", "n’t"] {
+        let i = word.len().saturating_sub(suffix.len());
+
+        if i > 0
+            && word
+                .get(i..)
+                .is_some_and(|end| end.to_lowercase() == suffix)
+        {
+            return Some(i);
+        }
+    }
+
+    let folded = word.to_lowercase().replace('’', "'");
+
+    SPECIAL_WORDS
+        .iter()
+        .find(|(special, _)| *special == folded)
+        .map(|(_, i)| if word.starts_with('’') { i + 2 } else { *i })
+}
+
+/// Converts a token from its PTB form back to its usual form, e.g. "-LRB-"
+/// to "(" or "``" to "\"", which can be useful to process the output of tools
+/// trained on the PTB.
+pub fn unescape_penn_treebank(form: &str) -> &str {
+    match form {
+        "``" | "''" => "\"",
+        "`" => "'",
+        _ => ESCAPES
+            .iter()
+            .take(6)
+            .find(|(_, escaped)| *escaped == form)
+            .map(|(text, _)| *text)
+            .unwrap_or(form),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(word: &str) -> Option<(&str, &str)> {
+        split_contraction(word).map(|i| word.split_at(i))
+    }
+
+    #[test]
+    fn test_split_contraction() {
+        assert_eq!(split("don't"), Some(("do", "n't")));
+        assert_eq!(split("Can't"), Some(("Ca", "n't")));
+        assert_eq!(split("WON'T"), Some(("WO", "N'T")));
+        assert_eq!(split("didn’t"), Some(("did", "n’t")));
+        assert_eq!(split("cannot"), Some(("can", "not")));
+        assert_eq!(split("Gonna"), Some(("Gon", "na")));
+        assert_eq!(split("'Tis"), Some(("'T", "is")));
+        assert_eq!(split("’twas"), Some(("’t", "was")));
+        assert_eq!(split("n't"), None);
+        assert_eq!(split("paltoquet"), None);
+    }
+
+    #[test]
+    fn test_escape_punctuation() {
+        assert_eq!(escape_punctuation("(", false), Some("-LRB-"));
+        assert_eq!(escape_punctuation("}", false), Some("-RCB-"));
+        assert_eq!(escape_punctuation("\"", true), Some("``"));
+        assert_eq!(escape_punctuation("\"", false), Some("''"));
+        assert_eq!(escape_punctuation("“", false), Some("``"));
+        assert_eq!(escape_punctuation("'", true), Some("`"));
+        assert_eq!(escape_punctuation("'", false), None);
+        assert_eq!(escape_punctuation(",", false), None);
+
+        for form in ["-LRB-", "-RSB-", "``", "''", "`", ",", "word"] {
+            let text = unescape_penn_treebank(form);
+            let opening = form == "``" || form == "`";
+
+            assert_eq!(escape_punctuation(text, opening).unwrap_or(text), form);
+        }
+    }
+}
@@ -0,0 +1,939 @@
+// A small lexicon of frequent Chinese (simplified & traditional) and
+// Japanese words, meant to be good enough for social media text.
+pub static WORDS: &[&str] = &[
+    // Chinese
+    "我们",
+    "你们",
+    "他们",
+    "她们",
+    "它们",
+    "咱们",
+    "自己",
+    "大家",
+    "什么",
+    "怎么",
+    "怎么样",
+    "为什么",
+    "哪里",
+    "那里",
+    "这里",
+    "这个",
+    "那个",
+    "这些",
+    "那些",
+    "这样",
+    "那样",
+    "一个",
+    "一些",
+    "一下",
+    "一起",
+    "一定",
+    "一直",
+    "已经",
+    "还是",
+    "或者",
+    "但是",
+    "可是",
+    "因为",
+    "所以",
+    "如果",
+    "虽然",
+    "而且",
+    "然后",
+    "不过",
+    "只是",
+    "就是",
+    "也是",
+    "都是",
+    "不是",
+    "没有",
+    "可以",
+    "可能",
+    "应该",
+    "需要",
+    "知道",
+    "觉得",
+    "认为",
+    "希望",
+    "喜欢",
+    "爱情",
+    "朋友",
+    "家人",
+    "孩子",
+    "父母",
+    "老师",
+    "学生",
+    "学校",
+    "大学",
+    "中学",
+    "小学",
+    "学习",
+    "工作",
+    "公司",
+    "老板",
+    "同事",
+    "时间",
+    "时候",
+    "今天",
+    "明天",
+    "昨天",
+    "现在",
+    "以后",
+    "以前",
+    "之前",
+    "之后",
+    "最近",
+    "早上",
+    "晚上",
+    "中午",
+    "下午",
+    "上午",
+    "星期",
+    "周末",
+    "今年",
+    "明年",
+    "去年",
+    "问题",
+    "事情",
+    "东西",
+    "地方",
+    "国家",
+    "中国",
+    "美国",
+    "日本",
+    "法国",
+    "英国",
+    "德国",
+    "韩国",
+    "俄罗斯",
+    "世界",
+    "全球",
+    "北京",
+    "上海",
+    "广州",
+    "深圳",
+    "香港",
+    "台湾",
+    "天安门",
+    "北京大学",
+    "清华大学",
+    "政府",
+    "经济",
+    "社会",
+    "文化",
+    "历史",
+    "科学",
+    "技术",
+    "发展",
+    "研究",
+    "研究生",
+    "生命",
+    "起源",
+    "互联网",
+    "网络",
+    "手机",
+    "电脑",
+    "电视",
+    "新闻",
+    "媒体",
+    "微博",
+    "微信",
+    "视频",
+    "照片",
+    "音乐",
+    "电影",
+    "游戏",
+    "人民",
+    "人们",
+    "男人",
+    "女人",
+    "先生",
+    "女士",
+    "小姐",
+    "医生",
+    "医院",
+    "病毒",
+    "疫情",
+    "健康",
+    "身体",
+    "生活",
+    "世界杯",
+    "足球",
+    "比赛",
+    "市场",
+    "价格",
+    "银行",
+    "发生",
+    "开始",
+    "结束",
+    "继续",
+    "出现",
+    "表示",
+    "进行",
+    "成为",
+    "关于",
+    "对于",
+    "通过",
+    "根据",
+    "非常",
+    "特别",
+    "真的",
+    "还有",
+    "只有",
+    "所有",
+    "其他",
+    "其实",
+    "当然",
+    "一样",
+    "不同",
+    "重要",
+    "简单",
+    "美丽",
+    "漂亮",
+    "高兴",
+    "快乐",
+    "天气",
+    "下雨",
+    "城市",
+    "中心",
+    "北方",
+    "南方",
+    "大家好",
+    "谢谢",
+    "你好",
+    "再见",
+    "吃饭",
+    "睡觉",
+    "回家",
+    "看到",
+    "听到",
+    "说话",
+    "欢迎",
+    "认识",
+    "告诉",
+    "帮助",
+    "帮忙",
+    "准备",
+    "决定",
+    "选择",
+    "使用",
+    "利用",
+    "提供",
+    "提高",
+    "增加",
+    "减少",
+    "变化",
+    "改变",
+    "影响",
+    "支持",
+    "反对",
+    "参加",
+    "举行",
+    "发现",
+    "发布",
+    "发表",
+    "报道",
+    "记者",
+    "消息",
+    "信息",
+    "数据",
+    "系统",
+    "服务",
+    "产品",
+    "企业",
+    "行业",
+    "项目",
+    "计划",
+    "活动",
+    "会议",
+    "组织",
+    "管理",
+    "领导",
+    "总统",
+    "主席",
+    "总理",
+    "部长",
+    "官员",
+    "警察",
+    "军队",
+    "战争",
+    "和平",
+    "安全",
+    "危险",
+    "环境",
+    "能源",
+    "气候",
+    "污染",
+    "教育",
+    "考试",
+    "家庭",
+    "房子",
+    "汽车",
+    "飞机",
+    "火车",
+    "地铁",
+    "机场",
+    "车站",
+    "酒店",
+    "饭店",
+    "餐厅",
+    "商店",
+    "超市",
+    "手术",
+    "疫苗",
+    "中文",
+    "汉语",
+    "英语",
+    "语言",
+    "文字",
+    "图书馆",
+    "电话",
+    "邮件",
+    "网站",
+    "软件",
+    "人工智能",
+    "智能",
+    "应用",
+    "平台",
+    "用户",
+    "账号",
+    "密码",
+    "分享",
+    "评论",
+    "点赞",
+    "关注",
+    "粉丝",
+    "直播",
+    "购物",
+    "旅游",
+    "旅行",
+    "风景",
+    "美食",
+    "好吃",
+    "好看",
+    "好玩",
+    "可爱",
+    "开心",
+    "难过",
+    "生气",
+    "害怕",
+    "担心",
+    "相信",
+    "记得",
+    "忘记",
+    "明白",
+    "理解",
+    "同意",
+    "介绍",
+    "解释",
+    "讨论",
+    "回答",
+    "答案",
+    "办法",
+    "方法",
+    "方面",
+    "方式",
+    "情况",
+    "结果",
+    "原因",
+    "目的",
+    "意思",
+    "意见",
+    "观点",
+    "态度",
+    "能力",
+    "机会",
+    "条件",
+    "标准",
+    "水平",
+    "质量",
+    "数量",
+    "部分",
+    "全部",
+    "一切",
+    "每天",
+    "每个",
+    "有些",
+    "别人",
+    "对方",
+    "自由",
+    "民主",
+    "法律",
+    "政策",
+    "改革",
+    "开放",
+    "合作",
+    "交流",
+    "关系",
+    "国际",
+    "地区",
+    "农村",
+    "首都",
+    "全国",
+    "亚洲",
+    "欧洲",
+    "非洲",
+    "欧盟",
+    "联合国",
+    "小时",
+    "分钟",
+    "今后",
+    "将来",
+    "过去",
+    "未来",
+    "刚才",
+    "马上",
+    "立刻",
+    "正在",
+    "曾经",
+    "总是",
+    "经常",
+    "常常",
+    "有时",
+    "从来",
+    "一般",
+    "比较",
+    "更加",
+    "十分",
+    "有点",
+    "一点",
+    "多少",
+    "几个",
+    "怎样",
+    "哪个",
+    "哪些",
+    "勉强",
+    "努力",
+    "成功",
+    "失败",
+    "生日",
+    "新年",
+    "春节",
+    "节日",
+    "工人",
+    "农民",
+    "老人",
+    "女儿",
+    "儿子",
+    "妈妈",
+    "爸爸",
+    "哥哥",
+    "姐姐",
+    "弟弟",
+    "妹妹",
+    "丈夫",
+    "妻子",
+    "男朋友",
+    "女朋友",
+    "名字",
+    "身份",
+    "年轻",
+    "讲话",
+    "唱歌",
+    "跳舞",
+    "运动",
+    "跑步",
+    "游泳",
+    "休息",
+    "起床",
+    "上班",
+    "下班",
+    "上学",
+    "放学",
+    "开车",
+    "买东西",
+    "卖",
+    "买",
+    "写",
+    "读",
+    "听",
+    "吃",
+    "喝",
+    "走",
+    "跑",
+    "坐",
+    "站",
+    "住",
+    "用",
+    "做",
+    "找",
+    "等",
+    "叫",
+    "问",
+    "请",
+    "送",
+    "拿",
+    "带",
+    "算",
+    "懂",
+    "能",
+    "可",
+    "该",
+    "得",
+    "着",
+    "过",
+    "地",
+    "为",
+    "以",
+    "于",
+    "而",
+    "及",
+    "其",
+    "之",
+    "所",
+    "如",
+    "因",
+    "若",
+    "则",
+    "即",
+    "并",
+    "我",
+    "你",
+    "他",
+    "她",
+    "它",
+    "的",
+    "了",
+    "是",
+    "在",
+    "有",
+    "和",
+    "不",
+    "也",
+    "都",
+    "很",
+    "就",
+    "要",
+    "会",
+    "说",
+    "去",
+    "来",
+    "到",
+    "上",
+    "下",
+    "大",
+    "小",
+    "个",
+    "人",
+    "们",
+    "这",
+    "那",
+    "吗",
+    "呢",
+    "吧",
+    "啊",
+    "爱",
+    "看",
+    "想",
+    "好",
+    "对",
+    "从",
+    "把",
+    "被",
+    "给",
+    "让",
+    "跟",
+    "与",
+    "或",
+    "但",
+    "又",
+    "还",
+    "再",
+    "才",
+    "最",
+    "更",
+    "没",
+    "多",
+    "少",
+    "年",
+    "月",
+    "日",
+    "天",
+    "点",
+    "分",
+    // Traditional Chinese
+    "我們",
+    "你們",
+    "他們",
+    "這個",
+    "那個",
+    "什麼",
+    "為什麼",
+    "沒有",
+    "時間",
+    "時候",
+    "現在",
+    "問題",
+    "國家",
+    "中國",
+    "美國",
+    "日本人",
+    "臺灣",
+    "台灣",
+    "香港人",
+    "學生",
+    "學校",
+    "大學",
+    "東西",
+    "經濟",
+    "社會",
+    "歷史",
+    "網路",
+    "電腦",
+    "電視",
+    "新聞",
+    "歡迎",
+    "喜歡",
+    "謝謝",
+    "認識",
+    "電話",
+    "網站",
+    "資訊",
+    "學習",
+    "臺北",
+    "台北",
+    "發展",
+    "覺得",
+    "應該",
+    "這樣",
+    "那樣",
+    "為了",
+    "因為",
+    "還是",
+    "還有",
+    "開始",
+    "結束",
+    "關於",
+    "對於",
+    "國際",
+    "環境",
+    "醫生",
+    "醫院",
+    "選舉",
+    "總統",
+    "電影",
+    "音樂",
+    "遊戲",
+    "影片",
+    "老師",
+    "媽媽",
+    "姊姊",
+    "問",
+    "說話",
+    "聽",
+    "讀",
+    "寫",
+    "買",
+    "賣",
+    "開車",
+    "這裡",
+    "那裡",
+    "哪裡",
+    "怎麼",
+    "怎麼樣",
+    "已經",
+    "一個",
+    "一點",
+    "勉強",
+    "這",
+    "們",
+    "個",
+    "說",
+    "來",
+    "會",
+    "對",
+    "與",
+    "還",
+    "嗎",
+    // Japanese
+    "私",
+    "僕",
+    "俺",
+    "彼",
+    "彼女",
+    "あなた",
+    "私たち",
+    "皆さん",
+    "これ",
+    "それ",
+    "あれ",
+    "どれ",
+    "この",
+    "その",
+    "あの",
+    "どの",
+    "ここ",
+    "そこ",
+    "あそこ",
+    "どこ",
+    "こと",
+    "もの",
+    "とき",
+    "ため",
+    "よう",
+    "から",
+    "まで",
+    "より",
+    "ので",
+    "のに",
+    "けど",
+    "けれど",
+    "しかし",
+    "でも",
+    "そして",
+    "また",
+    "もう",
+    "まだ",
+    "とても",
+    "ちょっと",
+    "すごく",
+    "本当",
+    "本当に",
+    "今日",
+    "明日",
+    "昨日",
+    "来年",
+    "毎日",
+    "時代",
+    "日本語",
+    "東京",
+    "大阪",
+    "京都",
+    "英語",
+    "中国語",
+    "会社",
+    "仕事",
+    "電車",
+    "友達",
+    "家族",
+    "子供",
+    "映画",
+    "音楽",
+    "写真",
+    "天気",
+    "経済",
+    "食べ",
+    "食べる",
+    "食べた",
+    "食べます",
+    "行く",
+    "行き",
+    "行った",
+    "来る",
+    "来た",
+    "見る",
+    "見た",
+    "見ます",
+    "言う",
+    "言った",
+    "思う",
+    "思います",
+    "好き",
+    "大好き",
+    "嫌い",
+    "新しい",
+    "古い",
+    "大きい",
+    "小さい",
+    "良い",
+    "いい",
+    "悪い",
+    "楽しい",
+    "嬉しい",
+    "美味しい",
+    "おいしい",
+    "寿司",
+    "ラーメン",
+    "コーヒー",
+    "テレビ",
+    "ニュース",
+    "インターネット",
+    "コンピュータ",
+    "スマホ",
+    "ゲーム",
+    "アニメ",
+    "韓国",
+    "アメリカ",
+    "フランス",
+    "ドイツ",
+    "イギリス",
+    "来週",
+    "今週",
+    "先週",
+    "毎朝",
+    "朝",
+    "夜",
+    "昼",
+    "午前",
+    "午後",
+    "人々",
+    "皆",
+    "誰",
+    "何",
+    "何か",
+    "どう",
+    "どうして",
+    "なぜ",
+    "いつ",
+    "自分",
+    "一緒",
+    "一番",
+    "少し",
+    "たくさん",
+    "沢山",
+    "大丈夫",
+    "元気",
+    "綺麗",
+    "きれい",
+    "有名",
+    "簡単",
+    "難しい",
+    "面白い",
+    "高い",
+    "安い",
+    "長い",
+    "短い",
+    "早い",
+    "遅い",
+    "多い",
+    "少ない",
+    "暑い",
+    "寒い",
+    "可愛い",
+    "かわいい",
+    "欲しい",
+    "分かる",
+    "わかる",
+    "分かりました",
+    "知る",
+    "知って",
+    "知らない",
+    "読む",
+    "書く",
+    "聞く",
+    "話す",
+    "買う",
+    "待つ",
+    "使う",
+    "作る",
+    "働く",
+    "住む",
+    "遊ぶ",
+    "帰る",
+    "入る",
+    "出る",
+    "会う",
+    "持つ",
+    "始まる",
+    "終わる",
+    "考える",
+    "教える",
+    "覚える",
+    "忘れる",
+    "練習",
+    "料理",
+    "買い物",
+    "携帯",
+    "会議",
+    "駅",
+    "空港",
+    "病院",
+    "店",
+    "家",
+    "部屋",
+    "国",
+    "町",
+    "場所",
+    "言葉",
+    "名前",
+    "お金",
+    "気持ち",
+    "意味",
+    "質問",
+    "答え",
+    "理由",
+    "結果",
+    "情報",
+    "技術",
+    "開発",
+    "経験",
+    "試合",
+    "選手",
+    "野球",
+    "サッカー",
+    "北海道",
+    "沖縄",
+    "横浜",
+    "名古屋",
+    "福岡",
+    "漢字",
+    "ひらがな",
+    "カタカナ",
+    "お願い",
+    "お願いします",
+    "すみません",
+    "ごめんなさい",
+    "おやすみ",
+    "こんばんは",
+    "いただきます",
+    "ごちそうさま",
+    "はい",
+    "いいえ",
+    "そう",
+    "そうです",
+    "さん",
+    "ちゃん",
+    "くん",
+    "様",
+    "だけ",
+    "しか",
+    "ばかり",
+    "など",
+    "について",
+    "として",
+    "という",
+    "です",
+    "でした",
+    "ます",
+    "ました",
+    "ません",
+    "ましょう",
+    "だった",
+    "である",
+    "する",
+    "します",
+    "しました",
+    "して",
+    "した",
+    "しない",
+    "いる",
+    "います",
+    "いた",
+    "ある",
+    "あります",
+    "あった",
+    "ない",
+    "なる",
+    "なった",
+    "できる",
+    "ください",
+    "ありがとう",
+    "ございます",
+    "こんにちは",
+    "おはよう",
+    "さようなら",
+    "は",
+    "が",
+    "を",
+    "に",
+    "で",
+    "と",
+    "も",
+    "の",
+    "へ",
+    "や",
+    "か",
+    "ね",
+    "よ",
+    "な",
+];
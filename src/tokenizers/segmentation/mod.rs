@@ -0,0 +1,282 @@
+// Dictionary-based segmentation of the scripts not separating their words
+// using whitespace, e.g. Chinese or Japanese.
+//
+// Runs of such scripts are segmented by finding the path through the lattice
+// of lexicon words minimizing, first, the number of grapheme clusters not
+// covered by the lexicon and, second, the number of words.
+//
+// Unknown Han chars are emitted one by one, katakana runs being grouped since
+// they are typically loanwords.
+//
+// NOTE: Thai, Lao, Khmer & Burmese runs are kept whole until we can bundle
+// proper dictionaries for them, since a small lexicon cuts unknown words
+// around any short known word they contain, e.g. "ประมาณ".
+use std::collections::HashSet;
+use std::iter::once;
+
+use lazy_static::lazy_static;
+use unicode_segmentation::UnicodeSegmentation;
+
+mod cjk;
+
+struct Lexicon {
+    words: HashSet<&'static str>,
+    max_cluster_count: usize,
+}
+
+impl Lexicon {
+    fn new(words: &[&'static str]) -> Self {
+        Self {
+            words: words.iter().copied().collect(),
+            max_cluster_count: words
+                .iter()
+                .map(|w| w.graphemes(true).count())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+lazy_static! {
+    static ref CJK_LEXICON: Lexicon = Lexicon::new(cjk::WORDS);
+}
+
+#[inline]
+fn is_han(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3005}'..='\u{3007}'
+            | '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{f900}'..='\u{faff}'
+            | '\u{20000}'..='\u{2fa1f}'
+    )
+}
+
+#[inline]
+fn is_hiragana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309f}')
+}
+
+// NOTE: the katakana middle dot "・" is punctuation
+#[inline]
+fn is_katakana(c: char) -> bool {
+    matches!(
+        c,
+        '\u{30a1}'..='\u{30fa}'
+            | '\u{30fc}'..='\u{30ff}'
+            | '\u{31f0}'..='\u{31ff}'
+            | '\u{ff66}'..='\u{ff9f}'
+    )
+}
+
+// NOTE: digits & punctuation of the following scripts are left out, so they
+// can be tokenized as usual
+#[inline]
+fn is_thai(c: char) -> bool {
+    matches!(c, '\u{e01}'..='\u{e3a}' | '\u{e40}'..='\u{e4e}')
+}
+
+#[inline]
+fn is_lao(c: char) -> bool {
+    matches!(c, '\u{e81}'..='\u{ecf}' | '\u{edc}'..='\u{edf}')
+}
+
+#[inline]
+fn is_khmer(c: char) -> bool {
+    matches!(c, '\u{1780}'..='\u{17d3}' | '\u{17d7}' | '\u{17dc}'..='\u{17dd}')
+}
+
+#[inline]
+fn is_myanmar(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1000}'..='\u{103f}' | '\u{1050}'..='\u{108f}' | '\u{109a}'..='\u{109f}'
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Script {
+    /// Han, hiragana & katakana, which are mixed in Japanese
+    Cjk,
+    Thai,
+    Lao,
+    Khmer,
+    Myanmar,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Self> {
+        if is_han(c) || is_hiragana(c) || is_katakana(c) {
+            return Some(Self::Cjk);
+        }
+
+        if is_thai(c) {
+            return Some(Self::Thai);
+        }
+
+        if is_lao(c) {
+            return Some(Self::Lao);
+        }
+
+        if is_khmer(c) {
+            return Some(Self::Khmer);
+        }
+
+        if is_myanmar(c) {
+            return Some(Self::Myanmar);
+        }
+
+        None
+    }
+
+    fn contains(&self, c: char) -> bool {
+        Self::of(c) == Some(*self)
+    }
+
+    // Number of clusters at the beginning of the given text that should be
+    // considered as a single unknown word.
+    fn unknown_word_cluster_count(&self, text: &str) -> usize {
+        match self {
+            Self::Cjk if text.starts_with(is_katakana) => text
+                .graphemes(true)
+                .take_while(|g| g.starts_with(is_katakana))
+                .count(),
+            _ => 1,
+        }
+    }
+}
+
+pub(crate) fn requires_segmentation(c: char) -> bool {
+    Script::of(c).is_some()
+}
+
+/// Returns the script of the run of chars starting the given text, if it
+/// requires segmentation, along with the run's end.
+pub(crate) fn script_run(text: &str) -> Option<(Script, usize)> {
+    let script = Script::of(text.chars().next()?)?;
+
+    let end = text
+        .char_indices()
+        .find(|(_, c)| !script.contains(*c))
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+
+    Some((script, end))
+}
+
+// Number of unknown clusters & number of words of a path through the lattice.
+type Cost = (usize, usize);
+
+/// Segments a run of chars written in the given script into words.
+pub(crate) fn segment(script: Script, run: &str) -> Vec<&str> {
+    if script != Script::Cjk {
+        return vec![run];
+    }
+
+    let lexicon = &CJK_LEXICON;
+
+    let offsets = run
+        .grapheme_indices(true)
+        .map(|(i, _)| i)
+        .chain(once(run.len()))
+        .collect::<Vec<_>>();
+
+    let n = offsets.len() - 1;
+
+    // NOTE: best[j] is the cost of the best path to the j-th cluster boundary,
+    // along with the previous boundary
+    let mut best: Vec<Option<(Cost, usize)>> = vec![None; n + 1];
+    best[0] = Some(((0, 0), 0));
+
+    for i in 0..n {
+        let ((unknown, words), _) = match best[i] {
+            Some(path) => path,
+            None => continue,
+        };
+
+        let mut relax = |j: usize, cost: Cost| {
+            if best[j].map_or(true, |(best_cost, _)| cost < best_cost) {
+                best[j] = Some((cost, i));
+            }
+        };
+
+        for j in (i + 1)..=(i + lexicon.max_cluster_count).min(n) {
+            if lexicon.words.contains(&run[offsets[i]..offsets[j]]) {
+                relax(j, (unknown, words + 1));
+            }
+        }
+
+        let count = script.unknown_word_cluster_count(&run[offsets[i]..]);
+        relax(i + count, (unknown + count, words + 1));
+    }
+
+    let mut segments: Vec<&str> = Vec::new();
+    let mut j = n;
+
+    while j > 0 {
+        let (_, i) = best[j].unwrap();
+        segments.push(&run[offsets[i]..offsets[j]]);
+        j = i;
+    }
+
+    segments.reverse();
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cjk(run: &str) -> Vec<&str> {
+        segment(Script::Cjk, run)
+    }
+
+    #[test]
+    fn test_script_run() {
+        assert_eq!(script_run("北京。"), Some((Script::Cjk, 6)));
+        assert_eq!(script_run("北京 is"), Some((Script::Cjk, 6)));
+        assert_eq!(script_run("Paris北京"), None);
+        assert_eq!(script_run(""), None);
+        assert_eq!(script_run("สวัสดี ครับ"), Some((Script::Thai, 18)));
+        assert_eq!(script_run("ภาษาไทย123"), Some((Script::Thai, 21)));
+        assert_eq!(script_run("ສະບາຍດີ"), Some((Script::Lao, 21)));
+        assert_eq!(script_run("ខ្មែរ"), Some((Script::Khmer, 15)));
+        assert_eq!(script_run("မြန်မာ။"), Some((Script::Myanmar, 18)));
+    }
+
+    #[test]
+    fn test_segment() {
+        assert_eq!(cjk(""), Vec::<&str>::new());
+        assert_eq!(cjk("我爱北京天安门"), vec!["我", "爱", "北京", "天安门"]);
+        assert_eq!(cjk("研究生命起源"), vec!["研究", "生命", "起源"]);
+        assert_eq!(cjk("北京大学生"), vec!["北京", "大", "学生"]);
+        assert_eq!(
+            cjk("私は寿司が大好きです"),
+            vec!["私", "は", "寿司", "が", "大好き", "です"]
+        );
+        assert_eq!(
+            cjk("東京でプログラミングを勉強します"),
+            vec!["東京", "で", "プログラミング", "を", "勉強", "します"]
+        );
+        assert_eq!(cjk("北京欢迎你"), vec!["北京", "欢迎", "你"]);
+        assert_eq!(cjk("我们明天去机场"), vec!["我们", "明天", "去", "机场"]);
+        assert_eq!(cjk("歡迎來到台北"), vec!["歡迎", "來", "到", "台北"]);
+        assert_eq!(
+            cjk("日本語を話すのは難しい"),
+            vec!["日本語", "を", "話す", "の", "は", "難しい"]
+        );
+    }
+
+    #[test]
+    fn test_segment_southeast_asian_scripts() {
+        assert_eq!(
+            segment(Script::Thai, "ประเทศไทยมีประชากรประมาณหกสิบหกล้านคน"),
+            vec!["ประเทศไทยมีประชากรประมาณหกสิบหกล้านคน"]
+        );
+        assert_eq!(
+            segment(Script::Khmer, "ខ្ញុំស្រឡាញ់ប្រទេសកម្ពុជា"),
+            vec!["ខ្ញុំស្រឡាញ់ប្រទេសកម្ពុជា"]
+        );
+    }
+}
@@ -48,9 +48,15 @@ fn parens_are_closed(string: &str) -> bool {
 
 pub struct Sentences<'a> {
     input: &'a str,
+    len: usize,
 }
 
 impl<'a> Sentences<'a> {
+    #[inline]
+    fn offset(&self) -> usize {
+        self.len - self.input.len()
+    }
+
     fn split_at<'b>(&mut self, i: usize) -> &'b str
     where
         'a: 'b,
@@ -72,8 +78,16 @@ impl<'a> Iterator for Sentences<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_span().map(|(_, _, text)| text)
+    }
+}
+
+impl<'a> Sentences<'a> {
+    fn next_span(&mut self) -> Option<(usize, usize, &'a str)> {
         self.chomp();
 
+        let start = self.offset();
+
         if self.input.is_empty() {
             return None;
         }
@@ -100,23 +114,50 @@ impl<'a> Iterator for Sentences<'a> {
                 continue;
             }
 
-            return Some(self.split_at(find_offset + m.end()));
+            let text = self.split_at(find_offset + m.end());
+
+            return Some((start, start + text.len(), text));
         }
 
-        Some(self.split_at(self.input.len()))
+        let text = self.split_at(self.input.len());
+
+        Some((start, start + text.len(), text))
     }
 }
 
 impl<'a> From<&'a str> for Sentences<'a> {
     fn from(value: &'a str) -> Self {
-        Self { input: value }
+        Self {
+            input: value,
+            len: value.len(),
+        }
+    }
+}
+
+/// Iterator over the sentences of a text, yielding `(start, end, text)`
+/// triples where `start` and `end` are byte offsets into the original text.
+pub struct SentenceSpans<'a> {
+    inner: Sentences<'a>,
+}
+
+impl<'a> Iterator for SentenceSpans<'a> {
+    type Item = (usize, usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_span()
     }
 }
 
-pub fn split_sentences(text: &str) -> Sentences {
+pub fn split_sentences(text: &str) -> Sentences<'_> {
     Sentences::from(text)
 }
 
+pub fn split_sentence_spans(text: &str) -> SentenceSpans<'_> {
+    SentenceSpans {
+        inner: Sentences::from(text),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,7 +298,21 @@ mod tests {
         ];
 
         for (text, expected) in tests {
-            assert_eq!(split_sentences(&text).collect::<Vec<_>>(), expected);
+            assert_eq!(split_sentences(text).collect::<Vec<_>>(), expected);
+
+            for (start, end, sentence) in split_sentence_spans(text) {
+                assert_eq!(&text[start..end], sentence);
+            }
         }
     }
+
+    #[test]
+    fn test_sentence_spans() {
+        assert_eq!(
+            split_sentence_spans("  Hello. \n Été, bye-bye!\t").collect::<Vec<_>>(),
+            vec![(2, 8, "Hello."), (11, 26, "Été, bye-bye!")]
+        );
+
+        assert_eq!(split_sentence_spans(" \n").count(), 0);
+    }
 }
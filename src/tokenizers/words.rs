@@ -1,9 +1,11 @@
 /// A general purpose word tokenizer able to consider a lot of edge cases and
 /// typical entities all while remaining mostly language agnostic wrt languages
-/// separating their words using whitespace (not the asian languages, for instance).
+/// separating their words using whitespace.
 ///
 /// It was mostly designed for French and English, but it probably works with
-/// other latin languages out of the box.
+/// other latin languages out of the box. Chinese and Japanese runs are
+/// segmented using a small bundled lexicon, while Thai, Lao, Khmer and Burmese
+/// runs are kept whole for now.
 ///
 /// The emitted tokens are tagged by entity types (not part-of-speech).
 ///
@@ -13,112 +15,379 @@
 ///    could be considered as numbers, but you can analyze them further down the line.
 ///
 /// Here is a list of things we don't handle (yet):
-///   * Complex graphemes such as: u̲n̲d̲e̲r̲l̲i̲n̲e̲d̲ or ārrive
-///   * Multi-line hyphenation schemes
-///   * Junk found in the middle of a word token
-///   * It is not possible to keep apostrophes starting names
+///   * Multi-line hyphenation schemes (unless using the dehyphenate option)
 ///   * Some inclusive writing schemes not relying on specific punctuation
 ///
 // References:
 // https://github.com/Yomguithereal/fog/blob/master/test/tokenizers/words_test.py
 // https://github.com/Yomguithereal/fog/blob/master/fog/tokenizers/words.py
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::iter::once;
+use std::ops::Range;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use enumset::{EnumSet, EnumSetType};
 use lazy_static::lazy_static;
 use regex_automata::meta::Regex;
+use regex_automata::util::captures::Captures;
+use regex_automata::{Anchored, Input};
 use regex_syntax::escape as regex_escape;
+use unicode_segmentation::UnicodeSegmentation;
+use unidecode::unidecode;
+
+use super::clitics::{is_semitic_word, proclitic_boundaries, strip_diacritics};
+use super::compounds::Decompounder;
+use super::junk::{is_junk, JunkDetector, JunkReason};
+use super::ptb::{escape_punctuation, is_opening_context, split_contraction};
+use super::segmentation::{requires_segmentation, script_run, segment};
+use crate::stemmers::Stemmer;
+use crate::stopwords::{Language, Stoplist};
+use crate::utils::{join_hyphenated, reduce_lengthening, HYPHENATION_REGEX};
 
 static VOWELS: &str = "aáàâäąåoôóøeéèëêęiíïîıuúùûüyÿæœ";
 static CONSONANTS_APOSTROPHE: &str = "cdjlmnst";
 static LETTERS_START_NAME: &str = "dlmno";
-
-// NOTE: order IS important
-static SIMPLE_PATTERNS: [(&str, WordTokenKind); 9] = [
-    // Hashtags (must happen before emojis)
-    (
-        "(?i)^[#$]\\p{Alpha}[\\p{Alpha}\\p{Digit}]+\\b",
-        WordTokenKind::Hashtag,
-    ),
-    // Mentions
-    (
-        "(?i)^@\\p{Alpha}[\\p{Alpha}\\p{Digit}_]+\\b",
-        WordTokenKind::Mention,
-    ),
-    // Numbers (must happen before emojis)
-    (
-        "^-?\\p{Digit}+(?:[.,]\\p{Digit}+)?\\b",
-        WordTokenKind::Number,
-    ),
-    // Emojis
-    (
-        "^(?x)(?:
-            # Regional indicators
-            \\p{Regional_indicator}+
-            |
-            # Emoji ZWJ sequence with optional trailing junk
-            \\p{Emoji}(?:\u{200d}\\p{Emoji})+\u{fe0f}?
-            |
-            # Emoji modifier sequence
-            \\p{Emoji_Modifier_Base}(?:\u{fe0f}?\\p{Emoji_Modifier})?
-            |
-            # Emoji with optional trailing junk
-            \\p{Emoji_Presentation}\u{fe0f}?
-        )
-        ",
-        WordTokenKind::Emoji,
-    ),
-    // Abbreviations
-    (
-        "(?i)^(?:app?t|etc|[djs]r|prof|mlle|mgr|min|mrs|m[rs]|m|no|pp?|st|vs)\\.",
-        WordTokenKind::Word,
-    ),
-    // Urls
-    ("(?i)^https?://[^\\s,;]+", WordTokenKind::Url),
-    // Emails
-    (
-        "^(?i)[a-z0-9!#$%&*+\\-/=?^_`{|}~]{1,64}@[a-z]{2,8}\\.[a-z]{2,8}(?:\\.[a-z]{2,8})*",
-        WordTokenKind::Email,
-    ),
-    // Smileys
-    // (
-    //     "^(?:[\\-]+>|<[\\-]+|[<>]?[:;=8][\\-o\\*\\']?[\\)\\]\\(\\[dDpP/\\:\\}\\{@\\|\\\\]|[\\)\\]\\(\\[dDpP/\\:\\}\\{@\\|\\\\][\\-o\\*\\']?[:;=8]|[<:]3|\\^\\^)",
-    //     WordTokenKind::Smiley
-    // ),
-    // Acronyms
-    ("^\\p{Lu}(?:\\.\\p{Lu})+\\.?", WordTokenKind::Word),
-    // Early return for basic tokens
-    ("^\\p{Alpha}+(?:\\s|$)", WordTokenKind::Word),
+static SEGMENTED_SCRIPTS: &str =
+    "[\\p{Han}\\p{Hiragana}\\p{Katakana}\\p{Thai}\\p{Lao}\\p{Khmer}\\p{Myanmar}]";
+static EMAIL_LOCAL_CHARS: &str = "\\p{Alpha}\\p{Digit}!#$%&'*+\\-/=?^_`{|}~";
+static DOMAIN_LABEL: &str =
+    "[\\p{Alpha}\\p{Digit}](?:[\\p{Alpha}\\p{Digit}\\-]*[\\p{Alpha}\\p{Digit}])?";
+
+// NOTE: generic tlds are a curated subset of the public suffix list
+static TLDS: [&str; 301] = [
+    "corsica", "digital", "network", "website", "africa", "agency", "alsace", "berlin", "global",
+    "london", "museum", "online", "social", "travel", "cloud", "email", "media", "paris", "space",
+    "store", "tokyo", "world", "aero", "asia", "blog", "club", "coop", "info", "jobs", "live",
+    "mobi", "name", "news", "page", "post", "shop", "site", "tech", "wiki", "app", "art", "biz",
+    "bzh", "cat", "com", "dev", "edu", "eus", "gov", "int", "mil", "net", "nyc", "org", "pro",
+    "tel", "xyz", "ac", "ad", "ae", "af", "ag", "ai", "al", "am", "ao", "aq", "ar", "as", "at",
+    "au", "aw", "ax", "az", "ba", "bb", "bd", "be", "bf", "bg", "bh", "bi", "bj", "bm", "bn", "bo",
+    "br", "bs", "bt", "bw", "by", "bz", "ca", "cc", "cd", "cf", "cg", "ch", "ci", "ck", "cl", "cm",
+    "cn", "co", "cr", "cu", "cv", "cw", "cx", "cy", "cz", "de", "dj", "dk", "dm", "do", "dz", "ec",
+    "ee", "eg", "er", "es", "et", "eu", "fi", "fj", "fk", "fm", "fo", "fr", "ga", "gd", "ge", "gf",
+    "gg", "gh", "gi", "gl", "gm", "gn", "gp", "gq", "gr", "gs", "gt", "gu", "gw", "gy", "hk", "hm",
+    "hn", "hr", "ht", "hu", "id", "ie", "il", "im", "in", "io", "iq", "ir", "is", "it", "je", "jm",
+    "jo", "jp", "ke", "kg", "kh", "ki", "km", "kn", "kp", "kr", "kw", "ky", "kz", "la", "lb", "lc",
+    "li", "lk", "lr", "ls", "lt", "lu", "lv", "ly", "ma", "mc", "md", "me", "mg", "mh", "mk", "ml",
+    "mm", "mn", "mo", "mp", "mq", "mr", "ms", "mt", "mu", "mv", "mw", "mx", "my", "mz", "na", "nc",
+    "ne", "nf", "ng", "ni", "nl", "no", "np", "nr", "nu", "nz", "om", "pa", "pe", "pf", "pg", "ph",
+    "pk", "pl", "pm", "pn", "pr", "ps", "pt", "pw", "py", "qa", "re", "ro", "rs", "ru", "rw", "sa",
+    "sb", "sc", "sd", "se", "sg", "sh", "si", "sk", "sl", "sm", "sn", "so", "sr", "ss", "st", "sv",
+    "sx", "sy", "sz", "tc", "td", "tf", "tg", "th", "tj", "tk", "tl", "tm", "tn", "to", "tr", "tt",
+    "tv", "tw", "tz", "ua", "ug", "uk", "us", "uy", "uz", "va", "vc", "ve", "vg", "vi", "vn", "vu",
+    "wf", "ws", "ye", "yt", "za", "zm", "zw",
 ];
 
+// Generic tlds unlikely to be mistaken for words, the only ones recognized in
+// urls lacking both a scheme and www
+static BARE_GTLDS: [&str; 5] = ["com", "org", "net", "edu", "gov"];
+
+static DAY: &str = "(?:0?[1-9]|[12]\\d|3[01])";
+static MONTH: &str = "(?:0?[1-9]|1[0-2])";
+// NOTE: thousands may be separated by spaces, including (narrow) no-break ones
+static AMOUNT: &str = "-?(?:\\d{1,3}(?:[,. \u{a0}\u{202f}]\\d{3})+(?:[.,]\\d+)?|\\d+(?:[.,]\\d+)?)";
+static CURRENCY_SYMBOLS: &str = "[$€£¥₹₽₩¢]";
+static CURRENCY_CODES: &str = "(?:USD|EUR|GBP|CHF|JPY|CAD|AUD)";
+
 lazy_static! {
+    // NOTE: order IS important
+    static ref SIMPLE_PATTERNS: [(String, WordTokenKind); 13] = [
+        // Hashtags (must happen before emojis)
+        (
+            "(?i)^#\\p{Alpha}[\\p{Alpha}\\p{Digit}\\p{M}]+\\b".to_string(),
+            WordTokenKind::Hashtag,
+        ),
+        // Cashtags, e.g. $TSLA or $BRK.B
+        (
+            "^\\$[A-Z]{1,6}(?:\\.[A-Z]{1,2})?\\b".to_string(),
+            WordTokenKind::Cashtag,
+        ),
+        // Mentions
+        (
+            "(?i)^@\\p{Alpha}[\\p{Alpha}\\p{Digit}\\p{M}_]+\\b".to_string(),
+            WordTokenKind::Mention,
+        ),
+        // Emails (must happen before numbers)
+        // NOTE: quoted local parts are not supported
+        (
+            format!(
+                "^[{c}]+(?:\\.[{c}]+)*@(?:(?:{l}\\.)+\\p{{Alpha}}{{2,63}}|\\[\\d{{1,3}}(?:\\.\\d{{1,3}}){{3}}\\])",
+                c=EMAIL_LOCAL_CHARS, l=DOMAIN_LABEL
+            ),
+            WordTokenKind::Email,
+        ),
+        // Numbers (must happen before emojis)
+        // NOTE: a following segmented script char is trimmed, see `match_patterns`
+        (
+            format!("^-?\\p{{Digit}}+(?:[.,]\\p{{Digit}}+)?(?:\\b|{s})", s=SEGMENTED_SCRIPTS),
+            WordTokenKind::Number,
+        ),
+        // Emojis
+        (
+            "^(?x)(?:
+                # Regional indicators
+                \\p{Regional_indicator}+
+                |
+                # Emoji ZWJ sequence with optional trailing junk
+                \\p{Emoji}(?:\u{200d}\\p{Emoji})+\u{fe0f}?
+                |
+                # Emoji modifier sequence
+                \\p{Emoji_Modifier_Base}(?:\u{fe0f}?\\p{Emoji_Modifier})?
+                |
+                # Emoji with optional trailing junk
+                \\p{Emoji_Presentation}\u{fe0f}?
+            )
+            ".to_string(),
+            WordTokenKind::Emoji,
+        ),
+        // Urls
+        ("(?i)^https?://[^\\s,;]+".to_string(), WordTokenKind::Url),
+        // Scheme-less urls starting with www
+        (
+            format!("^(?i:www\\d{{0,3}})\\.(?:{l}\\.)*{l}(?::\\d{{1,5}})?(?:[/?#][^\\s,;]*)?", l=DOMAIN_LABEL),
+            WordTokenKind::Url,
+        ),
+        // Bare domains, with optional path, e.g. bit.ly/abc or medialab.sciencespo.fr
+        // NOTE: the tld must be lowercase so we don't catch sentences lacking a
+        // space after the final period, e.g. "This is.It". Most generic tlds
+        // are common words, e.g. "end.world", so they need a scheme or www.
+        // Without a path or a port, a single label is too ambiguous except
+        // before "com" or "org", e.g. "left.it", "main.rs" or "fig.net"
+        (
+            {
+                let tlds = TLDS
+                    .iter()
+                    .filter(|t| t.len() == 2 || BARE_GTLDS.contains(t))
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                format!(
+                    "^(?:(?:{l}\\.)+(?:{t})\\b(?::\\d{{1,5}}(?:[/?#][^\\s,;]*)?|[/?#][^\\s,;]*)|(?:{l}\\.)+(?:com|org)\\b|(?:{l}\\.){{2,}}(?:{t})\\b)",
+                    l=DOMAIN_LABEL,
+                    t=tlds.join("|"),
+                )
+            },
+            WordTokenKind::Url,
+        ),
+        // Abbreviations
+        (
+            "(?i)^(?:app?t|etc|[djs]r|prof|mlle|mgr|min|mrs|m[rs]|m|no|pp?|st|vs)\\.".to_string(),
+            WordTokenKind::Word,
+        ),
+        // Smileys (checked against their context, see `is_smiley`)
+        // NOTE: `8` and `B` eyes are not supported because they collide with lists
+        (
+            "^(?x)(?:
+                # Western smileys, e.g. :-) ;p :'( =D >:[
+                [<>]?[:;=][\\-o\\*']?[\\)\\]\\(\\[dDpP/\\\\\\}\\{@\\|3]
+                |
+                # Hearts, e.g. <3 </3
+                </?3+
+                |
+                # Eastern smileys, e.g. ^^ ^_^ -_-
+                \\^_*\\^
+                |
+                -_+-
+                |
+                # Laughing
+                [xX]D+\\b
+            )
+            ".to_string(),
+            WordTokenKind::Smiley,
+        ),
+        // Acronyms
+        ("^\\p{Lu}(?:\\.\\p{Lu})+\\.?".to_string(), WordTokenKind::Word),
+        // Early return for basic tokens
+        // NOTE: scripts requiring segmentation must not be swallowed, e.g. "iPhone手机"
+        (
+            format!("^\\p{{Alpha}}[\\p{{Alpha}}\\p{{M}}--{s}]*(?:\\s|$)", s=SEGMENTED_SCRIPTS),
+            WordTokenKind::Word,
+        ),
+    ];
+
+    // NOTE: order IS important
+    static ref DATE_TIME_PATTERNS: [(String, WordTokenKind); 6] = [
+        // ISO dates, with optional time, e.g. 2024-03-15 or 2024-03-15T14:30:00Z
+        (
+            format!(
+                "^\\d{{4}}-{m}-{d}(?:T\\d{{2}}:\\d{{2}}(?::\\d{{2}}(?:\\.\\d+)?)?(?:Z|[+\\-]\\d{{2}}:?\\d{{2}})?)?\\b",
+                d=DAY, m=MONTH
+            ),
+            WordTokenKind::Date,
+        ),
+        // Numeric dates, e.g. 2024/03/15, 15/03/2024 (French) or 03/15/24 (US)
+        // NOTE: dotted dates need a 4-digit year, since "12.5.10" is more
+        // likely to be a version number
+        (
+            format!(
+                "^(?:\\d{{4}}/{m}/{d}|(?:{d}/{m}|{m}/{d})/(?:\\d{{4}}|\\d{{2}})|(?:{d}\\.{m}|{m}\\.{d})\\.\\d{{4}}|(?:{d}-{m}|{m}-{d})-(?:\\d{{4}}|\\d{{2}}))\\b",
+                d=DAY, m=MONTH
+            ),
+            WordTokenKind::Date,
+        ),
+        // ISO durations, e.g. P3Y6M4DT12H30M5S or PT45M
+        (
+            "^P(?:(?:\\d+[YMWD])+(?:T(?:\\d+(?:\\.\\d+)?[HMS])+)?|T(?:\\d+(?:\\.\\d+)?[HMS])+)\\b".to_string(),
+            WordTokenKind::Duration,
+        ),
+        // Durations with explicit units, e.g. 1h30min, 45min, 2hrs or 3jours
+        // NOTE: "3h" or "90s" are too ambiguous to be considered durations
+        (
+            "(?i)^\\d+(?:h\\d+(?:min|mn|m)|min\\d+(?:s|sec)|(?:[.,]\\d+)?(?:mins?|mn|secs?|ms|hrs?|hours?|days?|jours?|j))\\b".to_string(),
+            WordTokenKind::Duration,
+        ),
+        // Times, e.g. 14:30, 14:30:15, 10:45pm, 10pm or 10a.m.
+        (
+            "(?i)^(?:(?:[01]?\\d|2[0-3]):[0-5]\\d(?::[0-5]\\d)?(?:[ap]m\\b|[ap]\\.m\\.)?|(?:1[0-2]|0?[1-9])(?:[ap]m\\b|[ap]\\.m\\.))".to_string(),
+            WordTokenKind::Time,
+        ),
+        // French times, e.g. 14h30 or 14h
+        (
+            "^(?:[01]?\\d|2[0-3])h(?:[0-5]\\d)?\\b".to_string(),
+            WordTokenKind::Time,
+        ),
+    ];
+
+    // NOTE: order IS important
+    static ref QUANTITY_PATTERNS: [(String, WordTokenKind); 4] = [
+        // Money amounts with prefixed currency, e.g. $3.88 or € 12 000
+        (
+            format!("^(?:{s}|{c})\\s?{a}\\b", s=CURRENCY_SYMBOLS, c=CURRENCY_CODES, a=AMOUNT),
+            WordTokenKind::Money,
+        ),
+        // Money amounts with suffixed currency, e.g. 12 000 €, 4M€ or 10 USD,
+        // but not 10 CADRES
+        (
+            format!("^{a}\\s?(?:[kKM]|Mds?|bn)?(?:{s}|{c}\\b)", s=CURRENCY_SYMBOLS, c=CURRENCY_CODES, a=AMOUNT),
+            WordTokenKind::Money,
+        ),
+        // Percentages, e.g. 50% or 12,5 %
+        (
+            format!("^{a}\\s?[%‰]", a=AMOUNT),
+            WordTokenKind::Percent,
+        ),
+        // French & English ordinals, e.g. 1er, 2nde, 3ème, 11º, XIXe or 21st
+        (
+            "^(?:\\d+(?:ère|ème|eme|ere|er|re|nde?|e|è|º|ª|st|nd|rd|th)|[IVXLC]{2,}(?:ème|e))\\b".to_string(),
+            WordTokenKind::Ordinal,
+        ),
+    ];
+
+    static ref QUANTITY_PATTERNS_REGEX: Regex = {
+        Regex::new_many(&QUANTITY_PATTERNS.iter().map(|(p, _)| p.as_str()).collect::<Vec<_>>()).unwrap()
+    };
+
+    static ref DATE_TIME_PATTERNS_REGEX: Regex = {
+        Regex::new_many(&DATE_TIME_PATTERNS.iter().map(|(p, _)| p.as_str()).collect::<Vec<_>>()).unwrap()
+    };
+
+    static ref HANDLE_PATTERNS: [(String, WordTokenKind, Platform); 4] = [
+        // Reddit users, e.g. u/someone or /u/someone
+        (
+            "^/?u/[\\w\\-]{3,20}\\b".to_string(),
+            WordTokenKind::Mention,
+            Platform::Reddit,
+        ),
+        // Subreddits, e.g. r/france or /r/france
+        (
+            "^/?r/\\w{2,21}\\b".to_string(),
+            WordTokenKind::Community,
+            Platform::Reddit,
+        ),
+        // Fediverse handles, e.g. @user@mastodon.social
+        (
+            format!(
+                "^@\\p{{Alpha}}(?:[\\p{{Alpha}}\\p{{Digit}}_.\\-]*[\\p{{Alpha}}\\p{{Digit}}_])?@(?:{l}\\.)+\\p{{Alpha}}{{2,63}}",
+                l=DOMAIN_LABEL
+            ),
+            WordTokenKind::Mention,
+            Platform::Mastodon,
+        ),
+        // Domain handles, e.g. @handle.bsky.social or @medialab.fr
+        (
+            format!("^@(?:{l}\\.)+(?:{t})\\b", l=DOMAIN_LABEL, t=TLDS.join("|")),
+            WordTokenKind::Mention,
+            Platform::Bluesky,
+        ),
+    ];
+
+    static ref HANDLE_PATTERNS_REGEXES: Vec<Regex> = {
+        HANDLE_PATTERNS.iter().map(|(p, _, _)| Regex::new(p).unwrap()).collect()
+    };
+
     static ref NAIVE_REGEX: Regex = {
         Regex::new("\\b\\w+\\b").unwrap()
     };
 
     static ref SIMPLE_PATTERNS_REGEX: Regex = {
-        Regex::new_many(&SIMPLE_PATTERNS.iter().map(|(p, _)| *p).collect::<Vec<_>>()).unwrap()
+        Regex::new_many(&SIMPLE_PATTERNS.iter().map(|(p, _)| p.as_str()).collect::<Vec<_>>()).unwrap()
+    };
+
+    // NOTE: the email pattern is costly, so it is only tried when the current
+    // chunk contains an "@", its index being kept by a pattern never matching
+    static ref SIMPLE_PATTERNS_WITHOUT_EMAIL_REGEX: Regex = {
+        Regex::new_many(&SIMPLE_PATTERNS.iter().map(|(p, kind)| without_email(p, *kind)).collect::<Vec<_>>()).unwrap()
     };
 
     static ref APOSTROPHE_REGEX: Regex = {
         let patterns = [
             // 'nt 'hui
-            "(?i)^(aujourd['’]hui|\\p{Alpha}+n['’]t)",
+            "(?i)^(aujourd['’]hui|\\p{Alpha}[\\p{Alpha}\\p{M}]*n['’]t)",
             // English shenanigans
             "(?i)^(['’](?:twas|tis|ll|re|ve|[dms]))\\b",
             // Roman articles
-            &format!("(?i)^((?:qu|[{c}])['’])[{v}h#@]\\p{{Alpha}}*\\b", c=CONSONANTS_APOSTROPHE, v=VOWELS),
+            &format!("(?i)^((?:qu|[{c}])['’])[{v}h#@][\\p{{Alpha}}\\p{{M}}]*\\b", c=CONSONANTS_APOSTROPHE, v=VOWELS),
             // English contractions
             "(?i)^(\\p{Alpha})['’](?:ll|re|ve|[dms])\\b",
             // Names like O'Hara and N'diaye
-            &format!("(?i)^((?:[{l}])['’]\\p{{Alpha}}+)\\b", l=LETTERS_START_NAME)
+            &format!("(?i)^((?:[{l}])['’]\\p{{Alpha}}[\\p{{Alpha}}\\p{{M}}]*)\\b", l=LETTERS_START_NAME)
+        ];
+
+        Regex::new_many(&patterns).unwrap()
+    };
+
+    static ref APOSTROPHE_NAME_REGEX: Regex =
+        Regex::new("^['’]\\p{Lu}\\p{Ll}[\\p{Alpha}\\p{M}]*\\b").unwrap();
+
+    // Names like D'Alembert
+    static ref ELIDED_NAME_REGEX: Regex =
+        Regex::new("^D['’]\\p{Lu}\\p{Ll}[\\p{Alpha}\\p{M}]*\\b").unwrap();
+
+    static ref PENN_TREEBANK_PUNCT_REGEX: Regex = Regex::new("^(?:\\.\\.+|--+)").unwrap();
+
+    static ref INCLUSIVE_WORD_REGEX: Regex = {
+        let markers = "eresse|trice|rice|euse|ère|ice|ne|le|te|se|ve|e";
+        let long_markers = "eresse|trice|rice|euse|ère|ice|e";
+
+        // NOTE: every pattern captures the base, the feminine marker and the
+        // optional plural mark
+        let patterns = [
+            // étudiant·es, acteur·rice·s
+            format!("^(\\p{{Alpha}}{{2,}})[·•‧∙]({m})(?:[.·•‧∙]?(s))?\\b", m=markers),
+            // étudiant.e.s, acteur.rice.s
+            format!("^(\\p{{Alpha}}{{2,}})\\.({m})(?:\\.?(s))?\\b", m=long_markers),
+            // étudiant(e)s, étudiant(e)(s)
+            format!("^(\\p{{Alpha}}{{2,}})\\(({m})\\)(?:(s)\\b|\\((s)\\))?", m=markers),
+            // lecteurs/trices, élu/e
+            format!("^(\\p{{Alpha}}{{2,}})/({m})(s)?\\b", m=markers),
         ];
 
+        // NOTE: after a period, short markers are easily mistaken for a missing
+        // space, e.g. "venu.le lendemain", so they must follow their matching
+        // masculine ending, e.g. "citoyen.ne.s"
+        let short_markers = [("n", "ne"), ("l", "le"), ("t", "te"), ("x", "se"), ("f", "ve")];
+
+        let patterns = patterns.into_iter().chain(short_markers.iter().map(|(ending, marker)| {
+            format!("^(\\p{{Alpha}}+{})\\.({})(?:\\.?(s))?\\b", ending, marker)
+        })).collect::<Vec<_>>();
+
         Regex::new_many(&patterns).unwrap()
     };
 
     static ref COMPOUND_WORD_REGEX: Regex = {
-        Regex::new("^[\\p{Alpha}\\p{Digit}]+(?:[\\-_·]+[\\p{Alpha}\\p{Digit}]['’\\p{Alpha}\\p{Digit}]*)+").unwrap()
+        Regex::new("^[\\p{Alpha}\\p{Digit}][\\p{Alpha}\\p{Digit}\\p{M}]*(?:[\\-_·]+[\\p{Alpha}\\p{Digit}]['’\\p{Alpha}\\p{Digit}\\p{M}]*)+").unwrap()
     };
 
     static ref FRENCH_ILLEGAL_COMPOUND_REGEX: Regex = {
@@ -135,76 +404,251 @@ fn is_ascii_junk_or_whitespace(c: char) -> bool {
     c <= '\x1f' || c.is_whitespace()
 }
 
+// Invisible and control chars found in the middle of words, typically due to
+// copy-pasting or text extraction, e.g. zero-width spaces or soft hyphens.
 #[inline]
-pub fn starts_with_vowel(c: &str) -> bool {
-    VOWELS_REGEX.is_match(c)
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{ad}'
+            | '\u{61c}'
+            | '\u{180e}'
+            | '\u{200b}'..='\u{200f}'
+            | '\u{202a}'..='\u{202e}'
+            | '\u{2060}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{feff}'
+    ) || (c.is_control() && !c.is_whitespace())
+}
+
+// Zero-width spaces & (non-)joiners are legit in scripts relying on them,
+// e.g. Persian or Thai, but are just noise after latin, greek or cyrillic
+// letters.
+#[inline]
+fn is_legit_invisible(previous: char, c: char) -> bool {
+    matches!(c, '\u{200b}'..='\u{200d}') && previous > '\u{52f}'
+}
+
+fn strip_invisible(word: &str) -> Option<String> {
+    if !word.chars().any(is_invisible) {
+        return None;
+    }
+
+    let mut stripped = String::with_capacity(word.len());
+
+    for c in word.chars() {
+        if is_invisible(c)
+            && !stripped
+                .chars()
+                .next_back()
+                .is_some_and(|previous| is_legit_invisible(previous, c))
+        {
+            continue;
+        }
+
+        stripped.push(c);
+    }
+
+    (stripped.len() < word.len()).then_some(stripped)
 }
 
-// A token can be considered as junk if:
-//   1. it is too long to be a plausible word (> 30 bytes)
-//   2. it has more than 3 consecutive identical letters
-//   3. has more than 7 consecutive consonants
-//   4. has more than 6 consecutive vowels
-//   5. has no vowels (except stuff like "l'" or "qu'")
-pub fn is_junk(string: &str) -> bool {
-    // Word too long
-    if string.len() > 30 {
-        return true;
-    }
-
-    let mut total_vowel_count: u8 = 0;
-    let mut consecutive_vowel_count: u8 = 0;
-    let mut consecutive_consonant_count: u8 = 0;
-    let mut has_punct = false;
-    let mut last_char_opt: Option<(char, usize)> = None;
-
-    for (i, c) in string.char_indices() {
-        if let Some((last_c, count)) = &mut last_char_opt {
-            if c != *last_c {
-                *last_c = c;
-            } else if *count == 3 {
-                // Too much consecutive identical letters
-                return true;
+// Returns the end of the url once trailing punctuation has been trimmed,
+// keeping closing brackets when they are balanced within the url, e.g.
+// "https://en.wikipedia.org/wiki/Paris_(mythology)".
+fn trim_url_end(url: &str) -> usize {
+    let mut end = url.len();
+
+    while let Some(c) = url[..end].chars().next_back() {
+        let opening = match c {
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            '>' => '<',
+            '.' | ',' | ':' | ';' | '!' | '?' | '\'' | '"' | '’' | '”' | '»' => {
+                end -= c.len_utf8();
+                continue;
+            }
+            _ => break,
+        };
+
+        let balance = url[..end].chars().fold(0isize, |balance, other| {
+            if other == opening {
+                balance + 1
+            } else if other == c {
+                balance - 1
             } else {
-                *count += 1;
+                balance
             }
-        } else {
-            last_char_opt = Some((c, 1));
+        });
+
+        if balance >= 0 {
+            break;
         }
 
-        if starts_with_vowel(&string[i..]) {
-            consecutive_consonant_count = 0;
-            total_vowel_count = total_vowel_count.saturating_add(1);
-            consecutive_vowel_count += 1;
-        } else if c.is_alphabetic() {
-            consecutive_vowel_count = 0;
-            consecutive_consonant_count += 1;
-        } else {
-            consecutive_consonant_count = 0;
-            consecutive_vowel_count = 0;
-            has_punct = true;
+        end -= c.len_utf8();
+    }
+
+    end
+}
+
+#[inline]
+fn starts_with_alphanumeric(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(|c| c.is_alphanumeric())
+}
+
+#[inline]
+pub fn starts_with_vowel(c: &str) -> bool {
+    VOWELS_REGEX.is_match(c)
+}
+
+// Masculine endings to replace when appending a feminine marker, e.g.
+// "acteur" + "rice" -> "actrice".
+static INCLUSIVE_MARKER_ENDINGS: [(&str, &str, &str); 8] = [
+    ("eresse", "eur", "eresse"),
+    ("trice", "teur", "trice"),
+    ("rice", "eur", "rice"),
+    ("ice", "eur", "rice"),
+    ("euse", "eur", "euse"),
+    ("ère", "er", "ère"),
+    ("se", "x", "se"),
+    ("ve", "f", "ve"),
+];
+
+// Masculine endings taking a bare "e" marker after a period or within
+// parentheses, where it is easily mistaken for something else, e.g.
+// "fichier.e" or "Paris(e)".
+static INCLUSIVE_E_MARKER_ENDINGS: [&str; 13] = [
+    "t", "é", "i", "u", "and", "ond", "ard", "ais", "ois", "in", "ur", "al", "ul",
+];
+
+fn inclusive_word_captures(text: &str) -> Option<Captures> {
+    let end = INCLUSIVE_WORD_REGEX.find(text)?.end();
+
+    // NOTE: the marker may start a compound, e.g. "fini.e-mail"
+    if text[end..].starts_with('-') {
+        return None;
+    }
+
+    let mut caps = INCLUSIVE_WORD_REGEX.create_captures();
+    INCLUSIVE_WORD_REGEX.captures(text, &mut caps);
+
+    let base = caps.get_group(1).unwrap();
+
+    if &text[caps.get_group(2).unwrap().range()] == "e" && text[base.end..].starts_with(['.', '('])
+    {
+        let base = text[base.range()].to_lowercase();
+
+        if !INCLUSIVE_E_MARKER_ENDINGS
+            .iter()
+            .any(|ending| base.ends_with(ending))
+        {
+            return None;
+        }
+    }
+
+    Some(caps)
+}
+
+/// Expands a French inclusive writing form, e.g. "étudiant.e.s",
+/// "acteur·rice·s" or "lecteurs/trices", into its masculine and feminine
+/// forms. Returns `None` if the given string is not an inclusive form.
+pub fn expand_inclusive_form(word: &str) -> Option<(String, String)> {
+    let caps = inclusive_word_captures(word)?;
+    let m = caps.get_match()?;
+
+    if m.end() != word.len() {
+        return None;
+    }
+
+    let mut base = &word[caps.get_group(1).unwrap().range()];
+    let marker = &word[caps.get_group(2).unwrap().range()];
+    let plural = caps.get_group(3).is_some() || caps.get_group(4).is_some();
+
+    // NOTE: "lecteurs/trices" has its plural mark on the base
+    if plural && word[base.len()..].starts_with('/') {
+        base = base.strip_suffix('s').unwrap_or(base);
+    }
+
+    let mut masculine = base.to_string();
+    let mut feminine = match INCLUSIVE_MARKER_ENDINGS
+        .iter()
+        .find(|(m, ending, _)| *m == marker && base.ends_with(ending))
+    {
+        Some((_, ending, feminine_ending)) => {
+            base[..base.len() - ending.len()].to_string() + feminine_ending
         }
+        None => base.to_string() + marker,
+    };
 
-        // Too much consecutive vowels or consonants
-        if consecutive_vowel_count > 6 || consecutive_consonant_count > 7 {
-            return true;
+    if plural {
+        if !masculine.ends_with(['s', 'x']) {
+            masculine.push('s');
         }
+
+        feminine.push('s');
     }
 
-    // No vowels?
-    total_vowel_count == 0 && !has_punct
+    Some((masculine, feminine))
+}
+
+/// Platforms whose handle conventions can be recognized by the tokenizer,
+/// on top of the ubiquitous `@name` mentions.
+#[derive(Debug, EnumSetType)]
+pub enum Platform {
+    /// `u/someone` mentions and `r/france` communities
+    Reddit,
+    /// `@user@mastodon.social` mentions
+    Mastodon,
+    /// `@handle.bsky.social` mentions
+    Bluesky,
+}
+
+/// How elided articles, e.g. "l'" in "l'amour", should be tokenized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ElisionMode {
+    /// "l'amour" -> "l'", "amour"
+    #[default]
+    Split,
+    /// "l'amour" -> "l'amour"
+    Keep,
+    /// "l'amour" -> "amour"
+    Strip,
+}
+
+/// How Arabic & Hebrew words should be tokenized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SemiticMode {
+    /// "وَالْكِتَابُ" -> "وَالْكِتَابُ"
+    #[default]
+    Keep,
+    /// "وَالْكِتَابُ" -> "وَالْكِتَابُ" normalized as "والكتاب"
+    Normalize,
+    /// "وَالْكِتَابُ" -> "وَ", "الْ", "كِتَابُ" normalized as "و", "ال", "كتاب"
+    Split,
 }
 
 #[derive(Debug, EnumSetType)]
 pub enum WordTokenKind {
     Word,
     Hashtag,
+    Cashtag,
     Mention,
+    Community,
     Emoji,
     Punctuation,
     Number,
     Url,
     Email,
+    Smiley,
+    Date,
+    Time,
+    Duration,
+    Money,
+    Percent,
+    Ordinal,
+    /// Token matched by a user-defined pattern, whose label can be found in
+    /// the token's `label` field
+    Custom,
 }
 
 impl WordTokenKind {
@@ -212,12 +656,22 @@ impl WordTokenKind {
         match self {
             Self::Word => "word",
             Self::Hashtag => "hashtag",
+            Self::Cashtag => "cashtag",
             Self::Mention => "mention",
+            Self::Community => "community",
             Self::Emoji => "emoji",
             Self::Punctuation => "punct",
             Self::Number => "number",
             Self::Url => "url",
             Self::Email => "email",
+            Self::Smiley => "smiley",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Duration => "duration",
+            Self::Money => "money",
+            Self::Percent => "percent",
+            Self::Ordinal => "ordinal",
+            Self::Custom => "custom",
         }
     }
 }
@@ -229,12 +683,22 @@ impl FromStr for WordTokenKind {
         Ok(match s {
             "word" => Self::Word,
             "hashtag" => Self::Hashtag,
+            "cashtag" => Self::Cashtag,
             "mention" => Self::Mention,
+            "community" => Self::Community,
             "emoji" => Self::Emoji,
             "punct" => Self::Punctuation,
             "number" => Self::Number,
             "url" => Self::Url,
             "email" => Self::Email,
+            "smiley" => Self::Smiley,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "duration" => Self::Duration,
+            "money" => Self::Money,
+            "percent" => Self::Percent,
+            "ordinal" => Self::Ordinal,
+            "custom" => Self::Custom,
             _ => return Err(format!("unknown word token kind {}", s)),
         })
     }
@@ -244,20 +708,54 @@ impl FromStr for WordTokenKind {
 pub struct WordToken<'a> {
     pub kind: WordTokenKind,
     pub text: &'a str,
+    /// Byte offset of the token's start in the tokenized text.
+    pub offset: usize,
+    /// Form of the token, when it differs from its surface text, e.g. when
+    /// expanding inclusive writing.
+    pub normalized: Option<Cow<'a, str>>,
+    /// Label of the user-defined pattern having matched the token, if any.
+    pub label: Option<Arc<str>>,
 }
 
 impl<'a> WordToken<'a> {
     pub fn new(text: &'a str, kind: WordTokenKind) -> Self {
-        Self { kind, text }
-    }
-
-    pub fn word(text: &'a str) -> Self {
         Self {
-            kind: WordTokenKind::Word,
+            kind,
             text,
+            offset: 0,
+            normalized: None,
+            label: None,
         }
     }
 
+    pub fn word(text: &'a str) -> Self {
+        Self::new(text, WordTokenKind::Word)
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_normalized<T: Into<Cow<'a, str>>>(mut self, normalized: T) -> Self {
+        self.normalized = Some(normalized.into());
+        self
+    }
+
+    /// Returns the normalized form of the token if any, or its surface text.
+    pub fn form(&self) -> &str {
+        self.normalized.as_deref().unwrap_or(self.text)
+    }
+
+    /// Byte offset of the token's end (exclusive) in the tokenized text.
+    pub fn end(&self) -> usize {
+        self.offset + self.text.len()
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.offset..self.end()
+    }
+
     pub fn to_pair(&self) -> (String, WordTokenKind) {
         (self.text.to_string(), self.kind)
     }
@@ -270,11 +768,213 @@ impl<'a> WordToken<'a> {
     }
 }
 
+/// A normalization step applied to the tokens emitted by a [`WordTokenizer`],
+/// whose result is found in their `normalized` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Not applied to punctuation, whose PTB forms, e.g. "-LRB-", are uppercase
+    Lowercase,
+    /// Transliterate to ASCII, e.g. "éléphant" -> "elephant"
+    AsciiFold,
+    /// See [`crate::utils::reduce_lengthening`]
+    ReduceLengthening,
+    /// Replace typographic apostrophes with ASCII ones, e.g. "l’" -> "l'"
+    UnifyApostrophes,
+    /// Only applied to word tokens
+    Stem(Stemmer),
+}
+
+impl Normalization {
+    fn apply<'a>(&self, form: Cow<'a, str>, kind: WordTokenKind) -> Cow<'a, str> {
+        match self {
+            // NOTE: punctuation may carry a PTB form, e.g. "-LRB-"
+            Self::Lowercase
+                if kind != WordTokenKind::Punctuation && form.chars().any(char::is_uppercase) =>
+            {
+                Cow::Owned(form.to_lowercase())
+            }
+            Self::AsciiFold if !form.is_ascii() => {
+                let folded = unidecode(&form);
+
+                // NOTE: unidecode has nothing to offer for some chars, e.g. emojis
+                if folded.is_empty() {
+                    form
+                } else {
+                    Cow::Owned(folded)
+                }
+            }
+            Self::ReduceLengthening => {
+                let reduced = reduce_lengthening(&form);
+
+                if reduced.len() < form.len() {
+                    Cow::Owned(reduced)
+                } else {
+                    form
+                }
+            }
+            Self::UnifyApostrophes if form.contains(['’', 'ʼ', '‘']) => {
+                Cow::Owned(form.replace(['’', 'ʼ', '‘'], "'"))
+            }
+            Self::Stem(stemmer) if kind == WordTokenKind::Word => match form {
+                Cow::Borrowed(word) => stemmer.stem(word),
+                Cow::Owned(word) => Cow::Owned(stemmer.stem(&word).into_owned()),
+            },
+            _ => form,
+        }
+    }
+}
+
+/// Priority of a user-defined token pattern relative to the built-in ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternPriority {
+    /// Checked before any built-in pattern
+    First,
+    /// Checked right before the first simple built-in pattern of the given kind
+    Before(WordTokenKind),
+    /// Checked right after the last simple built-in pattern of the given kind
+    After(WordTokenKind),
+    /// Checked after every built-in pattern
+    Last,
+}
+
+fn without_email(pattern: &str, kind: WordTokenKind) -> &str {
+    match kind {
+        WordTokenKind::Email => "^[^\\s\\S]",
+        _ => pattern,
+    }
+}
+
+type Lexicon = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+type LabelledKinds = Vec<(Option<Arc<str>>, WordTokenKind)>;
+
+// Simple patterns compiled along with user-defined ones, the latter being
+// labelled. Patterns with the `First` priority are compiled on their own since
+// they must be checked before handles, dates etc.
+struct CustomPatterns {
+    first: Option<(Regex, LabelledKinds)>,
+    regex: Regex,
+    regex_without_email: Regex,
+    patterns: LabelledKinds,
+}
+
+impl CustomPatterns {
+    fn new(custom: &[(Arc<str>, String, PatternPriority)]) -> Self {
+        let mut patterns: Vec<(Option<Arc<str>>, &str, WordTokenKind)> = SIMPLE_PATTERNS
+            .iter()
+            .map(|(pattern, kind)| (None, pattern.as_str(), *kind))
+            .collect();
+
+        let anchored = custom
+            .iter()
+            .map(|(_, pattern, _)| format!("^(?:{})", pattern))
+            .collect::<Vec<_>>();
+
+        let mut first = Vec::new();
+
+        for ((label, _, priority), pattern) in custom.iter().zip(anchored.iter()) {
+            if *priority == PatternPriority::First {
+                first.push((Some(label.clone()), pattern.as_str()));
+                continue;
+            }
+
+            // NOTE: `None` matches any built-in kind
+            let builtin = |kind: Option<WordTokenKind>| {
+                patterns
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, (label, _, k))| {
+                        label.is_none() && kind.map_or(true, |kind| kind == *k)
+                    })
+                    .map(|(i, _)| i)
+            };
+
+            // NOTE: custom patterns sharing a priority are kept in insertion order
+            let i = match priority {
+                PatternPriority::First => unreachable!(),
+                PatternPriority::Before(kind) => builtin(Some(*kind)).next(),
+                PatternPriority::After(kind) => builtin(Some(*kind))
+                    .next_back()
+                    .and_then(|i| builtin(None).find(|j| *j > i)),
+                PatternPriority::Last => None,
+            }
+            .unwrap_or(patterns.len());
+
+            patterns.insert(
+                i,
+                (Some(label.clone()), pattern.as_str(), WordTokenKind::Custom),
+            );
+        }
+
+        Self {
+            first: (!first.is_empty()).then(|| {
+                (
+                    Regex::new_many(&first.iter().map(|(_, p)| *p).collect::<Vec<_>>()).unwrap(),
+                    first
+                        .into_iter()
+                        .map(|(label, _)| (label, WordTokenKind::Custom))
+                        .collect(),
+                )
+            }),
+            regex: Regex::new_many(&patterns.iter().map(|(_, p, _)| *p).collect::<Vec<_>>())
+                .unwrap(),
+            regex_without_email: Regex::new_many(
+                &patterns
+                    .iter()
+                    .map(|(_, p, kind)| without_email(p, *kind))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap(),
+            patterns: patterns
+                .into_iter()
+                .map(|(label, _, kind)| (label, kind))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct WordTokensOptions {
+    platforms: EnumSet<Platform>,
+    dehyphenate: bool,
+    compound_lexicon: Option<Lexicon>,
+    expand_inclusive_forms: bool,
+    dates_and_times: bool,
+    quantities: bool,
+    elision_mode: ElisionMode,
+    keep_apostrophe_names: bool,
+    semitic_mode: SemiticMode,
+    decompounder: Option<Arc<Decompounder>>,
+    penn_treebank: bool,
+    custom_patterns: Option<Arc<CustomPatterns>>,
+}
+
 pub struct WordTokens<'a> {
+    source: &'a str,
     input: &'a str,
+    options: WordTokensOptions,
+    pending: VecDeque<WordToken<'a>>,
+    parts: VecDeque<WordToken<'a>>,
+    // Number of parentheses currently open on the line
+    parens: usize,
 }
 
 impl<'a> WordTokens<'a> {
+    fn with_options(input: &'a str, options: WordTokensOptions) -> Self {
+        Self {
+            source: input,
+            input,
+            options,
+            pending: VecDeque::new(),
+            parts: VecDeque::new(),
+            parens: 0,
+        }
+    }
+
+    #[inline]
+    fn offset(&self) -> usize {
+        self.source.len() - self.input.len()
+    }
+
     fn split_at<'b>(&mut self, i: usize) -> &'b str
     where
         'a: 'b,
@@ -286,23 +986,215 @@ impl<'a> WordTokens<'a> {
     }
 
     fn chomp(&mut self) {
-        self.input = self
+        let input = self
             .input
-            .trim_start_matches(|c: char| is_ascii_junk_or_whitespace(c));
+            .trim_start_matches(|c: char| is_ascii_junk_or_whitespace(c) || is_invisible(c));
+
+        if self.input[..self.input.len() - input.len()].contains('\n') {
+            self.parens = 0;
+        }
+
+        self.input = input;
+    }
+
+    // Returns whether the given smiley candidate is not rather legit
+    // punctuation, e.g. "cases:(a)", "ratio 2:3", "f(x)=D(x)" or "(voir :(
+    // triste)".
+    fn is_smiley(&self, smiley: &str) -> bool {
+        if self.input[smiley.len()..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric())
+        {
+            return false;
+        }
+
+        let mouth = smiley.chars().next_back().unwrap();
+
+        if self.parens > 0 && matches!(mouth, '(' | '[' | '{') {
+            return false;
+        }
+
+        // NOTE: "3" & letter mouths are only trusted after a whitespace
+        if smiley.starts_with(['<', '>', ':', ';', '=']) && mouth.is_alphanumeric() {
+            return match self.source[..self.offset()].chars().next_back() {
+                None => true,
+                Some(c) => c.is_whitespace() || matches!(c, '(' | '[' | '{' | '"' | '«' | '“'),
+            };
+        }
+
+        true
+    }
+
+    fn match_patterns<P>(
+        &self,
+        regex: &Regex,
+        patterns: &[(P, WordTokenKind)],
+    ) -> Option<(usize, usize)> {
+        let m = regex.find(self.input)?;
+
+        // NOTE: some patterns only match the empty string in context, e.g. "\b"
+        if m.is_empty() {
+            return None;
+        }
+
+        let i = m.pattern().as_usize();
+        let kind = patterns[i].1;
+
+        match kind {
+            WordTokenKind::Smiley if !self.is_smiley(&self.input[..m.end()]) => None,
+            WordTokenKind::Url => Some((trim_url_end(&self.input[..m.end()]), i)),
+            // NOTE: numbers may be glued to a segmented script, e.g. "2024年"
+            WordTokenKind::Number => {
+                let last = self.input[..m.end()].chars().next_back().unwrap();
+
+                if requires_segmentation(last) {
+                    Some((m.end() - last.len_utf8(), i))
+                } else {
+                    Some((m.end(), i))
+                }
+            }
+            _ => Some((m.end(), i)),
+        }
+    }
+
+    fn parse_patterns<'b, P>(
+        &mut self,
+        regex: &Regex,
+        patterns: &[(P, WordTokenKind)],
+    ) -> Option<WordToken<'b>>
+    where
+        'a: 'b,
+    {
+        let (end, i) = self.match_patterns(regex, patterns)?;
+
+        let offset = self.offset();
+        let text = self.split_at(end);
+
+        Some(WordToken::new(text, patterns[i].1).with_offset(offset))
+    }
+
+    fn parse_custom_patterns(
+        &mut self,
+        patterns: &[(Option<Arc<str>>, WordTokenKind)],
+        matched: Option<(usize, usize)>,
+    ) -> Option<WordToken<'a>> {
+        let (end, i) = matched?;
+        let (label, kind) = &patterns[i];
+
+        let offset = self.offset();
+        let mut token = WordToken::new(self.split_at(end), *kind).with_offset(offset);
+        token.label = label.clone();
+
+        Some(token)
     }
 
-    fn parse_simple_pattern<'b>(&mut self) -> Option<WordToken<'b>>
+    fn parse_handle<'b>(&mut self) -> Option<WordToken<'b>>
     where
         'a: 'b,
     {
-        SIMPLE_PATTERNS_REGEX.find(self.input).map(|m| {
-            let text = self.split_at(m.end());
+        for ((_, kind, platform), regex) in
+            HANDLE_PATTERNS.iter().zip(HANDLE_PATTERNS_REGEXES.iter())
+        {
+            if !self.options.platforms.contains(*platform) {
+                continue;
+            }
 
-            WordToken {
-                kind: SIMPLE_PATTERNS[m.pattern()].1,
-                text,
+            if let Some(m) = regex.find(self.input) {
+                let offset = self.offset();
+                let text = self.split_at(m.end());
+
+                return Some(WordToken::new(text, *kind).with_offset(offset));
             }
-        })
+        }
+
+        None
+    }
+
+    fn parse_hyphenated_word<'b>(&mut self) -> Option<WordToken<'b>>
+    where
+        'a: 'b,
+    {
+        let mut caps = HYPHENATION_REGEX.create_captures();
+        HYPHENATION_REGEX.captures(Input::new(self.input).anchored(Anchored::Yes), &mut caps);
+
+        let end = caps.get_match()?.end();
+
+        let left = &self.input[caps.get_group(1).unwrap().range()];
+        let right = &self.input[caps.get_group(2).unwrap().range()];
+
+        let joined = match &self.options.compound_lexicon {
+            Some(lexicon) => join_hyphenated(left, right, lexicon.as_ref()),
+            None => join_hyphenated(left, right, |_| false),
+        };
+
+        let offset = self.offset();
+        let text = self.split_at(end);
+
+        Some(
+            WordToken::word(text)
+                .with_offset(offset)
+                .with_normalized(joined),
+        )
+    }
+
+    fn parse_inclusive_word<'b>(&mut self) -> Option<&'b str>
+    where
+        'a: 'b,
+    {
+        inclusive_word_captures(self.input)
+            .map(|caps| self.split_at(caps.get_match().unwrap().end()))
+    }
+
+    // Returns whether the current chunk, i.e. the input up to the next
+    // whitespace, contains an "@" and may therefore be an email.
+    fn may_be_email(&self) -> bool {
+        self.input
+            .split(char::is_whitespace)
+            .next()
+            .is_some_and(|chunk| chunk.contains('@'))
+    }
+
+    fn simple_patterns_regex(&self) -> &'static Regex {
+        if self.may_be_email() {
+            &SIMPLE_PATTERNS_REGEX
+        } else {
+            &SIMPLE_PATTERNS_WITHOUT_EMAIL_REGEX
+        }
+    }
+
+    // Returns whether the word ending at the given index is actually the
+    // beginning of a domain name or an email, e.g. "sciences-po.fr"
+    fn is_url_or_email_start(&self, word_end: usize) -> bool {
+        if !matches!(self.input[word_end..].chars().next(), Some('.' | '@')) {
+            return false;
+        }
+
+        matches!(
+            self.match_patterns(self.simple_patterns_regex(), &*SIMPLE_PATTERNS),
+            Some((end, i)) if end > word_end
+                && matches!(SIMPLE_PATTERNS[i].1, WordTokenKind::Url | WordTokenKind::Email)
+        )
+    }
+
+    fn parse_script_run(&mut self) -> Option<WordToken<'a>> {
+        let (script, end) = script_run(self.input)?;
+
+        if self.is_url_or_email_start(end) {
+            return None;
+        }
+
+        let offset = self.offset();
+        let run = self.split_at(end);
+        let mut start = offset;
+
+        for word in segment(script, run) {
+            self.pending
+                .push_back(WordToken::word(word).with_offset(start));
+            start += word.len();
+        }
+
+        self.pending.pop_front()
     }
 
     fn parse_compound_word<'b>(&mut self) -> Option<&'b str>
@@ -310,6 +1202,10 @@ impl<'a> WordTokens<'a> {
         'a: 'b,
     {
         if let Some(m) = COMPOUND_WORD_REGEX.find(self.input) {
+            if self.is_url_or_email_start(m.end()) {
+                return None;
+            }
+
             if !FRENCH_ILLEGAL_COMPOUND_REGEX.is_match(&self.input[..m.end()]) {
                 return Some(self.split_at(m.end()));
             } else {
@@ -329,7 +1225,8 @@ impl<'a> WordTokens<'a> {
         None
     }
 
-    fn parse_apostrophe_issues<'b>(&mut self) -> Option<&'b str>
+    // NOTE: the boolean indicates whether the text is an elided article
+    fn parse_apostrophe_issues<'b>(&mut self) -> Option<(&'b str, bool)>
     where
         'a: 'b,
     {
@@ -338,71 +1235,551 @@ impl<'a> WordTokens<'a> {
 
         if caps.is_match() {
             let i = caps.get_group(1).unwrap().end;
+            let is_elision = caps.pattern().unwrap().as_usize() == 2;
 
-            return Some(self.split_at(i));
+            return Some((self.split_at(i), is_elision));
         }
 
         None
     }
-}
 
-impl<'a> Iterator for WordTokens<'a> {
-    type Item = WordToken<'a>;
+    fn parse_elision(&mut self, article: WordToken<'a>) -> Option<WordToken<'a>> {
+        match self.options.elision_mode {
+            ElisionMode::Split => Some(article),
+            mode => {
+                let token = match self.parse_token() {
+                    Some(token) => token,
+                    None => return Some(article),
+                };
+
+                // NOTE: we don't attach nor strip articles before mentions,
+                // hashtags etc., nor attach them to expanded forms spanning
+                // several tokens
+                if token.kind != WordTokenKind::Word
+                    || token.offset != article.end()
+                    || (mode == ElisionMode::Keep && !self.pending.is_empty())
+                {
+                    self.pending.push_front(token);
+                    return Some(article);
+                }
+
+                if mode == ElisionMode::Strip {
+                    return Some(token);
+                }
+
+                let text = &self.source[article.offset..token.end()];
+                let mut attached = WordToken::word(text).with_offset(article.offset);
+
+                if let Some(normalized) = token.normalized {
+                    attached = attached.with_normalized(format!("{}{}", article.text, normalized));
+                }
+
+                Some(attached)
+            }
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.chomp();
+    // NOTE: words are split into parts, e.g. proclitics or compound parts,
+    // once fully parsed so that those never end up being parsed twice
+    fn parse_semitic_word(&mut self, token: WordToken<'a>) -> Option<WordToken<'a>> {
+        let boundaries = match self.options.semitic_mode {
+            SemiticMode::Split => proclitic_boundaries(token.text),
+            _ => Vec::new(),
+        };
+
+        let part_count = boundaries.len() + 1;
+        let mut start = 0;
+
+        for (i, end) in boundaries
+            .into_iter()
+            .chain(once(token.text.len()))
+            .enumerate()
+        {
+            let text = &token.text[start..end];
+            let mut part = WordToken::word(text).with_offset(token.offset + start);
 
-        if self.input.is_empty() {
-            return None;
-        }
+            let form = if part_count == 1 {
+                token.form().to_string()
+            } else {
+                strip_invisible(text).unwrap_or_else(|| text.to_string())
+            };
 
-        if let Some(text) = self.parse_compound_word() {
-            return Some(WordToken::word(text));
-        }
+            let form = strip_diacritics(&form).unwrap_or(form);
 
-        let token = self.parse_simple_pattern();
+            // NOTE: the article loses its alef after "ل", e.g. "للكتاب"
+            if i + 2 == part_count && form == "ل" {
+                part = part.with_normalized("ال");
+            } else if form != text {
+                part = part.with_normalized(form);
+            }
 
-        if token.is_some() {
-            return token;
+            self.parts.push_back(part);
+            start = end;
+        }
+
+        self.parts.pop_front()
+    }
+
+    // NOTE: PTB forms are stored as normalized forms, so that the original
+    // text & span of the tokens are kept
+    fn parse_penn_treebank(&mut self, token: WordToken<'a>) -> WordToken<'a> {
+        fn unify_apostrophes(token: WordToken<'_>) -> WordToken<'_> {
+            if token.text.contains('’') {
+                let normalized = token.form().replace('’', "'");
+                token.with_normalized(normalized)
+            } else {
+                token
+            }
+        }
+
+        match token.kind {
+            WordTokenKind::Punctuation => {
+                let previous = self.source[..token.offset].chars().next_back();
+
+                match escape_punctuation(token.text, is_opening_context(previous)) {
+                    Some(escaped) => token.with_normalized(escaped),
+                    None => token,
+                }
+            }
+            WordTokenKind::Word => match split_contraction(token.text) {
+                Some(i) => {
+                    let (left, right) = token.text.split_at(i);
+
+                    self.parts.push_back(unify_apostrophes(
+                        WordToken::word(right).with_offset(token.offset + i),
+                    ));
+
+                    unify_apostrophes(WordToken::word(left).with_offset(token.offset))
+                }
+                None => unify_apostrophes(token),
+            },
+            _ => token,
+        }
+    }
+
+    fn parse_compound_parts(&mut self, token: &WordToken<'a>, decompounder: &Decompounder) {
+        for part in decompounder.split(token.text).into_iter().flatten() {
+            let mut part_token = WordToken::word(part.text).with_offset(token.offset + part.offset);
+
+            if part.stem.len() < part.text.len() {
+                part_token = part_token.with_normalized(part.stem);
+            }
+
+            self.parts.push_back(part_token);
+        }
+    }
+
+    fn parse_token(&mut self) -> Option<WordToken<'a>> {
+        if let Some(token) = self.pending.pop_front() {
+            return Some(token);
+        }
+
+        self.chomp();
+
+        if self.input.is_empty() {
+            return None;
+        }
+
+        // NOTE: every token starts where the chomped input starts
+        let offset = self.offset();
+
+        let custom = self.options.custom_patterns.clone();
+
+        if let Some((regex, patterns)) = custom.as_ref().and_then(|custom| custom.first.as_ref()) {
+            let matched = self.match_patterns(regex, patterns);
+
+            if matched.is_some() {
+                return self.parse_custom_patterns(patterns, matched);
+            }
+        }
+
+        // NOTE: ellipses & dashes are single tokens in the PTB
+        if self.options.penn_treebank {
+            if let Some(m) = PENN_TREEBANK_PUNCT_REGEX.find(self.input) {
+                let text = self.split_at(m.end());
+
+                return Some(WordToken::new(text, WordTokenKind::Punctuation).with_offset(offset));
+            }
+        }
+
+        if !self.options.platforms.is_empty() {
+            let token = self.parse_handle();
+
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        // NOTE: must happen before compound words, e.g. "2024-03-15"
+        if self.options.dates_and_times {
+            let token = self.parse_patterns(&DATE_TIME_PATTERNS_REGEX, &*DATE_TIME_PATTERNS);
+
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        if self.options.quantities {
+            let token = self.parse_patterns(&QUANTITY_PATTERNS_REGEX, &*QUANTITY_PATTERNS);
+
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        if self.options.dehyphenate {
+            let token = self.parse_hyphenated_word();
+
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        // NOTE: custom patterns are compiled along with the simple ones but
+        // must be able to take precedence over compound words, e.g. ISBNs
+        let mut custom_match = None;
+
+        if let Some(custom) = &custom {
+            let regex = if self.may_be_email() {
+                &custom.regex
+            } else {
+                &custom.regex_without_email
+            };
+
+            custom_match = self.match_patterns(regex, &custom.patterns);
+
+            if custom_match.is_some_and(|(_, i)| custom.patterns[i].0.is_some()) {
+                return self.parse_custom_patterns(&custom.patterns, custom_match);
+            }
+        }
+
+        // NOTE: scripts not relying on whitespace to separate words must be
+        // segmented, e.g. Chinese
+        let token = self.parse_script_run();
+
+        if token.is_some() {
+            return token;
+        }
+
+        // NOTE: must happen before simple patterns, e.g. "étudiant.es"
+        if let Some(text) = self.parse_inclusive_word() {
+            let token = WordToken::word(text).with_offset(offset);
+
+            if self.options.expand_inclusive_forms {
+                if let Some((masculine, feminine)) = expand_inclusive_form(text) {
+                    self.pending
+                        .push_back(token.clone().with_normalized(feminine));
+
+                    return Some(token.with_normalized(masculine));
+                }
+            }
+
+            return Some(token);
+        }
+
+        if let Some(text) = self.parse_compound_word() {
+            return Some(WordToken::word(text).with_offset(offset));
+        }
+
+        let token = match &custom {
+            Some(custom) => self.parse_custom_patterns(&custom.patterns, custom_match),
+            None => self.parse_patterns(self.simple_patterns_regex(), &*SIMPLE_PATTERNS),
+        };
+
+        if token.is_some() {
+            return token;
+        }
+
+        // NOTE: must happen before elisions, e.g. "D'Alembert"
+        if self.options.keep_apostrophe_names {
+            if let Some(m) = ELIDED_NAME_REGEX.find(self.input) {
+                return Some(WordToken::word(self.split_at(m.end())).with_offset(offset));
+            }
         }
 
         // NOTE: this is costly so we let it happen later on
-        if let Some(text) = self.parse_apostrophe_issues() {
-            return Some(WordToken::word(text));
+        if let Some((text, is_elision)) = self.parse_apostrophe_issues() {
+            let token = WordToken::word(text).with_offset(offset);
+
+            if is_elision {
+                return self.parse_elision(token);
+            }
+
+            return Some(token);
+        }
+
+        if self.options.keep_apostrophe_names {
+            if let Some(m) = APOSTROPHE_NAME_REGEX.find(self.input) {
+                return Some(WordToken::word(self.split_at(m.end())).with_offset(offset));
+            }
+        }
+
+        // NOTE: we work on extended grapheme clusters so that combining marks
+        // remain attached to their base character, e.g. "u̲n̲d̲e̲r̲l̲i̲n̲e̲d̲"
+        let mut graphemes = self.input.grapheme_indices(true);
+        let (_, grapheme) = graphemes.next().unwrap();
+
+        if !starts_with_alphanumeric(grapheme) {
+            let text = self.split_at(grapheme.len());
+
+            return Some(WordToken::new(text, WordTokenKind::Punctuation).with_offset(offset));
+        }
+
+        // NOTE: words may contain invisible chars, e.g. "Wo\u{200b}rd", that
+        // are stripped from their normalized form
+        let mut end = grapheme.len();
+
+        for (i, g) in graphemes {
+            if g.starts_with(requires_segmentation) {
+                break;
+            }
+
+            if starts_with_alphanumeric(g) {
+                end = i + g.len();
+                continue;
+            }
+
+            let previous = self.input[..end].chars().next_back().unwrap();
+
+            if !g
+                .chars()
+                .all(|c| is_invisible(c) && !is_legit_invisible(previous, c))
+            {
+                break;
+            }
+        }
+
+        let token = WordToken::word(self.split_at(end)).with_offset(offset);
+
+        match strip_invisible(token.text) {
+            Some(stripped) => Some(token.with_normalized(stripped)),
+            None => Some(token),
+        }
+    }
+}
+
+impl<'a> Iterator for WordTokens<'a> {
+    type Item = WordToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.parts.pop_front() {
+            return Some(token);
+        }
+
+        let mut token = self.parse_token()?;
+
+        if token.kind == WordTokenKind::Punctuation {
+            match token.text {
+                "(" => self.parens += 1,
+                ")" => self.parens = self.parens.saturating_sub(1),
+                _ => (),
+            }
+        }
+
+        if self.options.penn_treebank {
+            token = self.parse_penn_treebank(token);
         }
 
-        let mut chars = self.input.char_indices();
-        let (i, c) = chars.next().unwrap();
+        if token.kind != WordTokenKind::Word {
+            return Some(token);
+        }
 
-        if !c.is_alphanumeric() {
-            return Some(WordToken {
-                kind: WordTokenKind::Punctuation,
-                text: self.split_at(i + c.len_utf8()),
-            });
+        if self.options.semitic_mode != SemiticMode::Keep && is_semitic_word(token.text) {
+            return self.parse_semitic_word(token);
         }
 
-        let i = chars
-            .find(|(_, c)| !c.is_alphanumeric())
-            .map(|t| t.0)
-            .unwrap_or(self.input.len());
+        if let Some(decompounder) = self.options.decompounder.clone() {
+            self.parse_compound_parts(&token, &decompounder);
+        }
 
-        Some(WordToken::word(self.split_at(i)))
+        Some(token)
     }
 }
 
 impl<'a> From<&'a str> for WordTokens<'a> {
     fn from(value: &'a str) -> Self {
-        Self { input: value }
+        Self::with_options(value, WordTokensOptions::default())
+    }
+}
+
+/// Iterator adapter yielding word tokens along with their span expressed in
+/// chars rather than in bytes.
+///
+/// Tokens may go backwards in the text, e.g. compound parts following their
+/// compound, char offsets being counted from the previous token either way.
+pub struct CharOffsets<'a, I> {
+    text: &'a str,
+    inner: I,
+    byte_offset: usize,
+    char_offset: usize,
+}
+
+impl<'a, I> CharOffsets<'a, I>
+where
+    I: Iterator<Item = WordToken<'a>>,
+{
+    pub fn new(text: &'a str, inner: I) -> Self {
+        Self {
+            text,
+            inner,
+            byte_offset: 0,
+            char_offset: 0,
+        }
+    }
+}
+
+impl<'a, I> Iterator for CharOffsets<'a, I>
+where
+    I: Iterator<Item = WordToken<'a>>,
+{
+    type Item = (WordToken<'a>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.inner.next()?;
+
+        let start = if token.offset >= self.byte_offset {
+            self.char_offset + self.text[self.byte_offset..token.offset].chars().count()
+        } else {
+            self.char_offset - self.text[token.offset..self.byte_offset].chars().count()
+        };
+        let end = start + self.text[token.span()].chars().count();
+
+        self.byte_offset = token.end();
+        self.char_offset = end;
+
+        Some((token, start..end))
+    }
+}
+
+#[derive(Clone, Default)]
+struct MultiwordNode {
+    children: HashMap<String, usize>,
+    is_terminal: bool,
+}
+
+// Trie over the tokens of multi-word expressions, e.g. "pomme de terre", so
+// they can be matched in a single pass over the token stream.
+#[derive(Clone)]
+struct MultiwordTrie {
+    nodes: Vec<MultiwordNode>,
+    case_insensitive: bool,
+}
+
+impl MultiwordTrie {
+    fn new(case_insensitive: bool) -> Self {
+        Self {
+            nodes: vec![MultiwordNode::default()],
+            case_insensitive,
+        }
+    }
+
+    fn key<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.case_insensitive {
+            Cow::Owned(text.to_lowercase())
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    fn insert<'a, I: IntoIterator<Item = &'a str>>(&mut self, texts: I) {
+        let mut node = 0;
+
+        for text in texts {
+            let key = self.key(text).into_owned();
+
+            node = match self.nodes[node].children.get(&key) {
+                Some(child) => *child,
+                None => {
+                    self.nodes.push(MultiwordNode::default());
+
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(key, child);
+
+                    child
+                }
+            };
+        }
+
+        self.nodes[node].is_terminal = true;
+    }
+
+    fn child(&self, node: usize, text: &str) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .get(self.key(text).as_ref())
+            .copied()
+    }
+}
+
+// Iterator adapter merging the longest multi-word expressions found in the
+// token stream into single word tokens.
+struct Multiwords<'a, 't, I> {
+    text: &'a str,
+    inner: I,
+    trie: Option<&'t MultiwordTrie>,
+    buffer: VecDeque<WordToken<'a>>,
+}
+
+impl<'a, 't, I> Iterator for Multiwords<'a, 't, I>
+where
+    I: Iterator<Item = WordToken<'a>>,
+{
+    type Item = WordToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let trie = match self.trie {
+            Some(trie) => trie,
+            None => return self.inner.next(),
+        };
+
+        let mut node = 0;
+        let mut longest = 0;
+        let mut i = 0;
+
+        loop {
+            if i == self.buffer.len() {
+                match self.inner.next() {
+                    Some(token) => self.buffer.push_back(token),
+                    None => break,
+                }
+            }
+
+            match trie.child(node, self.buffer[i].text) {
+                Some(child) => node = child,
+                None => break,
+            }
+
+            i += 1;
+
+            if trie.nodes[node].is_terminal {
+                longest = i;
+            }
+        }
+
+        if longest < 2 {
+            return self.buffer.pop_front();
+        }
+
+        let offset = self.buffer[0].offset;
+        let end = self.buffer[longest - 1].end();
+
+        self.buffer.drain(..longest);
+
+        Some(WordToken::word(&self.text[offset..end]).with_offset(offset))
     }
 }
 
 #[derive(Clone, Default)]
 pub struct WordTokenizer {
-    stoplist_regex: Option<Regex>,
+    options: WordTokensOptions,
+    stoplist: Stoplist,
+    stop_patterns_regex: Option<Regex>,
     kind_blacklist: EnumSet<WordTokenKind>,
     min_token_char_count: Option<usize>,
     max_token_char_count: Option<usize>,
-    filter_junk: bool,
+    junk_detector: Option<JunkDetector>,
+    multiwords: Option<MultiwordTrie>,
+    normalizations: Vec<Normalization>,
 }
 
 impl WordTokenizer {
@@ -427,24 +1804,83 @@ impl WordTokenizer {
             }
         }
 
-        if let Some(pattern) = &self.stoplist_regex {
-            if pattern.is_match(token.text) {
+        if !self.stoplist.is_empty() && self.stoplist.contains(token.text) {
+            return false;
+        }
+
+        if let Some(regex) = &self.stop_patterns_regex {
+            if regex.is_match(token.text) {
                 return false;
             }
         }
 
-        if self.filter_junk && token.is_junk() {
+        if self.junk_reason(token).is_some() {
             return false;
         }
 
         true
     }
 
+    /// Returns the reason why the given token is filtered out as junk, if
+    /// the tokenizer was configured to do so.
+    pub fn junk_reason(&self, token: &WordToken) -> Option<JunkReason> {
+        match (&self.junk_detector, token.kind) {
+            (Some(detector), WordTokenKind::Word) => detector.check(token.text),
+            _ => None,
+        }
+    }
+
+    fn normalize<'a>(&self, mut token: WordToken<'a>) -> WordToken<'a> {
+        if self.normalizations.is_empty() {
+            return token;
+        }
+
+        let mut form = token.normalized.take().unwrap_or(Cow::Borrowed(token.text));
+
+        for normalization in self.normalizations.iter() {
+            form = normalization.apply(form, token.kind);
+        }
+
+        if form != token.text {
+            token.normalized = Some(form);
+        }
+
+        token
+    }
+
+    // NOTE: multi-word expressions are merged before filtering so that
+    // stopwords do not break them, e.g. "pomme de terre"
+    fn unfiltered_tokenize<'a, 'b>(&'a self, text: &'b str) -> Multiwords<'b, 'a, WordTokens<'b>> {
+        Multiwords {
+            text,
+            inner: WordTokens::with_options(text, self.options.clone()),
+            trie: self.multiwords.as_ref(),
+            buffer: VecDeque::new(),
+        }
+    }
+
     pub fn tokenize<'a, 'b>(&'a self, text: &'b str) -> impl Iterator<Item = WordToken<'b>> + 'a
     where
         'b: 'a,
     {
-        WordTokens::from(text).filter(|token| self.token_predicate(token))
+        self.unfiltered_tokenize(text)
+            .filter(|token| self.token_predicate(token))
+            .map(|token| self.normalize(token))
+    }
+
+    /// Returns the tokens filtered out as junk, along with the reason why,
+    /// so one can audit what the `filter_junk` option removes.
+    pub fn junk_tokens<'a, 'b>(
+        &'a self,
+        text: &'b str,
+    ) -> impl Iterator<Item = (WordToken<'b>, JunkReason)> + 'a
+    where
+        'b: 'a,
+    {
+        self.unfiltered_tokenize(text).filter_map(|token| {
+            let reason = self.junk_reason(&token)?;
+            Some((token, reason))
+        })
     }
 
     pub fn simple_tokenize<'a, 'b>(
@@ -456,18 +1892,35 @@ impl WordTokenizer {
     {
         NAIVE_REGEX
             .find_iter(text)
-            .map(|m| WordToken::word(&text[m.start()..m.end()]))
+            .map(|m| WordToken::word(&text[m.start()..m.end()]).with_offset(m.start()))
             .filter(|token| self.token_predicate(token))
+            .map(|token| self.normalize(token))
+    }
+
+    pub fn tokenize_with_char_offsets<'a, 'b>(
+        &'a self,
+        text: &'b str,
+    ) -> impl Iterator<Item = (WordToken<'b>, Range<usize>)> + 'a
+    where
+        'b: 'a,
+    {
+        CharOffsets::new(text, self.tokenize(text))
     }
 }
 
 #[derive(Default)]
 pub struct WordTokenizerBuilder {
-    stoplist: Vec<String>,
+    options: WordTokensOptions,
+    stoplist: Stoplist,
+    stop_patterns: Vec<String>,
+    custom_patterns: Vec<(Arc<str>, String, PatternPriority)>,
+    multiword_expressions: Vec<String>,
+    multiword_case_insensitive: bool,
+    normalizations: Vec<Normalization>,
     kind_blacklist: EnumSet<WordTokenKind>,
     min_token_char_count: Option<usize>,
     max_token_char_count: Option<usize>,
-    filter_junk: bool,
+    junk_detector: Option<JunkDetector>,
 }
 
 impl WordTokenizerBuilder {
@@ -476,7 +1929,7 @@ impl WordTokenizerBuilder {
     }
 
     pub fn insert_stopword<T: Into<String>>(&mut self, stopword: T) {
-        self.stoplist.push(stopword.into());
+        self.stoplist.insert(stopword.into());
     }
 
     pub fn stopwords<S, T>(mut self, words: T) -> Self
@@ -491,6 +1944,34 @@ impl WordTokenizerBuilder {
         self
     }
 
+    /// Add the bundled stopwords of the given language, see
+    /// [`crate::stopwords`]. Can be combined with custom stopwords.
+    pub fn language_stopwords(mut self, language: Language) -> Self {
+        self.stoplist.add_language(language);
+        self
+    }
+
+    /// Filter out tokens fully matching the given regex pattern, e.g. `\d+h`.
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is not a valid regex.
+    pub fn stop_pattern(mut self, pattern: &str) -> Self {
+        if let Err(err) = Regex::new(pattern) {
+            panic!("invalid stop pattern {}: {}", pattern, err);
+        }
+
+        self.stop_patterns.push(format!("^(?:{})$", pattern));
+        self
+    }
+
+    /// Filter out tokens starting with the given prefix, regardless of case.
+    pub fn stop_prefix<T: AsRef<str>>(mut self, prefix: T) -> Self {
+        self.stop_patterns
+            .push(format!("(?i)^{}", regex_escape(prefix.as_ref())));
+        self
+    }
+
     pub fn token_kind_blacklist<T: IntoIterator<Item = WordTokenKind>>(mut self, kinds: T) -> Self {
         self.kind_blacklist.clear();
 
@@ -521,104 +2002,283 @@ impl WordTokenizerBuilder {
     }
 
     pub fn filter_junk(mut self) -> Self {
-        self.filter_junk = true;
+        self.junk_detector = Some(JunkDetector::default());
         self
     }
 
-    pub fn build(self) -> WordTokenizer {
-        let mut stoplist_regex = None;
-
-        if !self.stoplist.is_empty() {
-            let mut stoplist_pattern = String::from("(?i)^(?:");
+    /// Same as [`WordTokenizerBuilder::filter_junk`] but relying on the given
+    /// detector, e.g. one tuned for a specific language.
+    pub fn filter_junk_with(mut self, detector: JunkDetector) -> Self {
+        self.junk_detector = Some(detector);
+        self
+    }
 
-            stoplist_pattern.push_str(
-                &self
-                    .stoplist
-                    .iter()
-                    .filter(|s| !s.is_empty())
-                    .map(|s| regex_escape(s))
-                    .collect::<Vec<_>>()
-                    .join("|"),
-            );
-            stoplist_pattern.push_str(")$");
+    /// Rejoin words hyphenated across line breaks, e.g. "gouver-\nnement",
+    /// into a single token whose `normalized` field holds the dehyphenated
+    /// word.
+    pub fn dehyphenate(mut self) -> Self {
+        self.options.dehyphenate = true;
+        self
+    }
 
-            stoplist_regex = Some(Regex::new(&stoplist_pattern).unwrap());
-        }
+    /// Same as [`WordTokenizerBuilder::dehyphenate`] but relying on the given
+    /// lexicon hook to decide whether a hyphenated form, e.g. "porte-monnaie",
+    /// is a true compound whose hyphen should be kept.
+    pub fn dehyphenate_with<F>(mut self, is_compound: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.options.dehyphenate = true;
+        self.options.compound_lexicon = Some(Arc::new(is_compound));
+        self
+    }
 
-        WordTokenizer {
-            stoplist_regex,
-            kind_blacklist: self.kind_blacklist,
-            min_token_char_count: self.min_token_char_count,
-            max_token_char_count: self.max_token_char_count,
-            filter_junk: self.filter_junk,
-        }
+    /// Emit both the masculine and feminine forms of French inclusive
+    /// writing, e.g. "étudiant.e.s", as two tokens sharing the same span, the
+    /// forms being found in their `normalized` field.
+    pub fn expand_inclusive_forms(mut self) -> Self {
+        self.options.expand_inclusive_forms = true;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use pretty_assertions::assert_eq;
+    /// Recognize the handle conventions of the given platforms as mentions
+    /// (or communities, in the case of subreddits).
+    pub fn platforms<T: IntoIterator<Item = Platform>>(mut self, platforms: T) -> Self {
+        self.options.platforms = platforms.into_iter().collect();
+        self
+    }
 
-    use super::*;
+    /// Recognize dates (ISO, French & US numeric forms), times (including
+    /// French forms such as "14h30") and durations as single tokens.
+    pub fn recognize_dates_and_times(mut self) -> Self {
+        self.options.dates_and_times = true;
+        self
+    }
 
-    fn tokens(text: &str) -> Vec<WordToken> {
-        WordTokens::from(text).collect()
+    /// Recognize money amounts, percentages and ordinals (French & English)
+    /// as single tokens.
+    pub fn recognize_quantities(mut self) -> Self {
+        self.options.quantities = true;
+        self
     }
 
-    fn w(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Word,
-            text,
-        }
+    /// Choose whether elided articles, e.g. "l'" in "l'amour", should be
+    /// split from the following word (default), kept attached to it or
+    /// stripped altogether.
+    pub fn elision_mode(mut self, mode: ElisionMode) -> Self {
+        self.options.elision_mode = mode;
+        self
     }
 
-    fn h(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Hashtag,
-            text,
-        }
+    /// Keep names starting with an apostrophe, typically transliterating an
+    /// ayin, e.g. "'Abd al-Rahman", or with an elided "D'", e.g. "D'Alembert",
+    /// as single tokens. Note that this will also capture capitalized words
+    /// opening a single-quoted passage, or following an elision in title-case
+    /// text, e.g. "D'Après".
+    pub fn keep_apostrophe_names(mut self) -> Self {
+        self.options.keep_apostrophe_names = true;
+        self
     }
 
-    fn m(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Mention,
-            text,
-        }
+    /// Choose whether Arabic & Hebrew words should be kept as is (default),
+    /// normalized by stripping their diacritics & tatweel or also split to
+    /// separate their proclitics, e.g. "وبالكتاب" -> "و", "ب", "ال", "كتاب".
+    /// Proclitics are only split when preceding the definite article, except
+    /// for the Hebrew conjunction "ו".
+    pub fn semitic_mode(mut self, mode: SemiticMode) -> Self {
+        self.options.semitic_mode = mode;
+        self
     }
 
-    fn n(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Number,
-            text,
-        }
+    /// Emit the parts of closed compounds, e.g. "Bundesverfassungsgericht",
+    /// as word tokens following the compound itself. Parts ending with a
+    /// linking morpheme, e.g. "Bundes", are normalized to their stem.
+    pub fn decompound(mut self, decompounder: Decompounder) -> Self {
+        self.options.decompounder = Some(Arc::new(decompounder));
+        self
     }
 
-    fn e(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Emoji,
-            text,
-        }
+    /// Follow the Penn Treebank conventions expected by most parsers & taggers
+    /// trained on it: "don't" -> "do", "n't", ellipses kept whole etc. PTB
+    /// forms of the tokens, e.g. "``" for an opening quote or "-LRB-" for
+    /// "(", are given as normalized forms, their text & span remaining those
+    /// of the original text. See also [`unescape_penn_treebank`].
+    ///
+    /// [`Normalization::Lowercase`] leaves punctuation untouched so that PTB
+    /// forms remain valid.
+    ///
+    /// [`unescape_penn_treebank`]: crate::tokenizers::unescape_penn_treebank
+    pub fn penn_treebank(mut self) -> Self {
+        self.options.penn_treebank = true;
+        self
     }
 
-    fn p(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Punctuation,
-            text,
+    /// Emit tokens matching the given regex pattern as [`WordTokenKind::Custom`]
+    /// tokens carrying the given label, e.g. to recognize DOIs or ISBNs.
+    ///
+    /// The pattern is anchored and checked at the given priority wrt the
+    /// built-in patterns, but always before compound words. Handles, dates
+    /// and quantities are always checked beforehand unless the priority is
+    /// [`PatternPriority::First`], so [`PatternPriority::Before`] and
+    /// [`PatternPriority::After`] only accept kinds produced by the simple
+    /// built-in patterns, e.g. [`WordTokenKind::Number`] or
+    /// [`WordTokenKind::Url`].
+    ///
+    /// # Panics
+    ///
+    /// If the pattern is not a valid regex, if it matches the empty string or
+    /// if its priority is relative to an unsupported kind.
+    pub fn custom_pattern<L: Into<Arc<str>>>(
+        mut self,
+        label: L,
+        pattern: &str,
+        priority: PatternPriority,
+    ) -> Self {
+        match Regex::new(pattern) {
+            Err(err) => panic!("invalid custom pattern {}: {}", pattern, err),
+            Ok(regex) if regex.is_match("") => {
+                panic!("custom pattern {} matches the empty string", pattern)
+            }
+            _ => (),
         }
-    }
 
-    fn u(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Url,
-            text,
+        if let PatternPriority::Before(kind) | PatternPriority::After(kind) = priority {
+            if !SIMPLE_PATTERNS.iter().any(|(_, k)| *k == kind) {
+                panic!(
+                    "custom pattern {} cannot be checked relative to {:?} tokens",
+                    pattern, kind
+                );
+            }
         }
+
+        self.custom_patterns
+            .push((label.into(), pattern.to_string(), priority));
+        self
     }
 
-    fn email(text: &str) -> WordToken {
-        WordToken {
-            kind: WordTokenKind::Email,
-            text,
+    /// Emit the given multi-word expressions, e.g. "New York" or "pomme de
+    /// terre", as single word tokens when encountered, the longest one
+    /// winning in case of overlap.
+    pub fn multiword_expressions<S, T>(mut self, expressions: T) -> Self
+    where
+        S: Into<String>,
+        T: IntoIterator<Item = S>,
+    {
+        self.multiword_expressions
+            .extend(expressions.into_iter().map(|e| e.into()));
+        self
+    }
+
+    /// Match multi-word expressions regardless of case, e.g. "new york".
+    pub fn multiword_case_insensitive(mut self) -> Self {
+        self.multiword_case_insensitive = true;
+        self
+    }
+
+    /// Append a step to the normalization pipeline applied, in order, to the
+    /// emitted tokens, e.g. lowercasing then stemming. The surface text and
+    /// offsets of the tokens are left untouched.
+    pub fn normalize(mut self, normalization: Normalization) -> Self {
+        self.normalizations.push(normalization);
+        self
+    }
+
+    pub fn build(mut self) -> WordTokenizer {
+        if !self.custom_patterns.is_empty() {
+            self.options.custom_patterns =
+                Some(Arc::new(CustomPatterns::new(&self.custom_patterns)));
+        }
+
+        let mut stop_patterns_regex = None;
+
+        if !self.stop_patterns.is_empty() {
+            stop_patterns_regex = Some(Regex::new_many(&self.stop_patterns).unwrap());
+        }
+
+        let mut multiwords = None;
+
+        if !self.multiword_expressions.is_empty() {
+            let mut trie = MultiwordTrie::new(self.multiword_case_insensitive);
+
+            // NOTE: expressions are tokenized the same way as the text they
+            // will be matched against
+            for expression in self.multiword_expressions.iter() {
+                trie.insert(
+                    WordTokens::with_options(expression, self.options.clone())
+                        .map(|token| token.text),
+                );
+            }
+
+            multiwords = Some(trie);
         }
+
+        WordTokenizer {
+            options: self.options,
+            stoplist: self.stoplist,
+            stop_patterns_regex,
+            kind_blacklist: self.kind_blacklist,
+            min_token_char_count: self.min_token_char_count,
+            max_token_char_count: self.max_token_char_count,
+            junk_detector: self.junk_detector,
+            multiwords,
+            normalizations: self.normalizations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    // NOTE: offsets are tested separately
+    fn strip_offset(token: WordToken) -> WordToken {
+        token.with_offset(0)
+    }
+
+    fn tokens(text: &str) -> Vec<WordToken<'_>> {
+        WordTokens::from(text).map(strip_offset).collect()
+    }
+
+    fn w(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Word)
+    }
+
+    fn h(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Hashtag)
+    }
+
+    fn c(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Cashtag)
+    }
+
+    fn m(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Mention)
+    }
+
+    fn n(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Number)
+    }
+
+    fn e(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Emoji)
+    }
+
+    fn p(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Punctuation)
+    }
+
+    fn u(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Url)
+    }
+
+    fn email(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Email)
+    }
+
+    fn s(text: &str) -> WordToken<'_> {
+        WordToken::new(text, WordTokenKind::Smiley)
     }
 
     #[test]
@@ -791,8 +2451,7 @@ mod tests {
                     p(":"),
                     w("ok"),
                     p("?"),
-                    w("Wo"),
-                    w("rd")
+                    w("Wo\x10rd").with_normalized("Word")
                 ]
             ),
             (
@@ -847,7 +2506,8 @@ mod tests {
                     h("#javascript"),
                     m("@Yomguithereal"),
                     p("!"),
-                    h("$cash")
+                    p("$"),
+                    w("cash")
                 ]
             ),
             (
@@ -1343,46 +3003,1592 @@ mod tests {
             ),
         ];
 
-        for (tt, expected) in tests {
-            assert_eq!(tokens(tt), expected);
+        for (tt, expected) in tests {
+            assert_eq!(tokens(tt), expected);
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        let text = "L'été,  va-t-on à  #Paris?\n\tÇa 🙏!";
+
+        let spans = WordTokens::from(text)
+            .map(|token| {
+                assert_eq!(&text[token.span()], token.text);
+                (token.text, token.offset, token.end())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            spans,
+            vec![
+                ("L'", 0, 2),
+                ("été", 2, 7),
+                (",", 7, 8),
+                ("va", 10, 12),
+                ("t", 13, 14),
+                ("on", 15, 17),
+                ("à", 18, 20),
+                ("#Paris", 22, 28),
+                ("?", 28, 29),
+                ("Ça", 31, 34),
+                ("🙏", 35, 39),
+                ("!", 39, 40)
+            ]
+        );
+
+        let tokenizer = WordTokenizer::new();
+
+        assert_eq!(
+            tokenizer
+                .simple_tokenize("le  chat")
+                .map(|token| token.span())
+                .collect::<Vec<_>>(),
+            vec![0..2, 4..8]
+        );
+
+        assert_eq!(
+            tokenizer
+                .tokenize_with_char_offsets(text)
+                .map(|(token, span)| (token.text, span))
+                .collect::<Vec<_>>(),
+            vec![
+                ("L'", 0..2),
+                ("été", 2..5),
+                (",", 5..6),
+                ("va", 8..10),
+                ("t", 11..12),
+                ("on", 13..15),
+                ("à", 16..17),
+                ("#Paris", 19..25),
+                ("?", 25..26),
+                ("Ça", 28..30),
+                ("🙏", 31..32),
+                ("!", 32..33)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_urls() {
+        assert_eq!(
+            tokens("Lisez www.lemonde.fr/politique, bit.ly/abc et medialab.sciencespo.fr!"),
+            vec![
+                w("Lisez"),
+                u("www.lemonde.fr/politique"),
+                p(","),
+                u("bit.ly/abc"),
+                w("et"),
+                u("medialab.sciencespo.fr"),
+                p("!")
+            ]
+        );
+
+        assert_eq!(
+            tokens("(see https://x.org/a). Or <https://x.org/b>, \"https://en.wikipedia.org/wiki/Paris_(mythology)\"..."),
+            vec![
+                p("("),
+                w("see"),
+                u("https://x.org/a"),
+                p(")"),
+                p("."),
+                w("Or"),
+                p("<"),
+                u("https://x.org/b"),
+                p(">"),
+                p(","),
+                p("\""),
+                u("https://en.wikipedia.org/wiki/Paris_(mythology)"),
+                p("\""),
+                p("."),
+                p("."),
+                p(".")
+            ]
+        );
+
+        assert_eq!(
+            tokens("medialab.sciences-po.fr m.facebook.com/page 20minutes.fr/politique www.example.zzz/a?b=c localhost.fr:8080/"),
+            vec![
+                u("medialab.sciences-po.fr"),
+                u("m.facebook.com/page"),
+                u("20minutes.fr/politique"),
+                u("www.example.zzz/a?b=c"),
+                u("localhost.fr:8080/")
+            ]
+        );
+
+        assert_eq!(
+            tokens("This is.It and lemonde.frites or index.html"),
+            vec![
+                w("This"),
+                w("is"),
+                p("."),
+                w("It"),
+                w("and"),
+                w("lemonde"),
+                p("."),
+                w("frites"),
+                w("or"),
+                w("index"),
+                p("."),
+                w("html")
+            ]
+        );
+
+        assert_eq!(
+            tokens("Bonjour.je left.it main.rs script.py README.md end.world fig.net"),
+            vec![
+                w("Bonjour"),
+                p("."),
+                w("je"),
+                w("left"),
+                p("."),
+                w("it"),
+                w("main"),
+                p("."),
+                w("rs"),
+                w("script"),
+                p("."),
+                w("py"),
+                w("README"),
+                p("."),
+                w("md"),
+                w("end"),
+                p("."),
+                w("world"),
+                w("fig"),
+                p("."),
+                w("net")
+            ]
+        );
+
+        assert_eq!(
+            tokens("wikipedia.org php.net/manual news.ycombinator.com www.paris.world https://end.world"),
+            vec![
+                u("wikipedia.org"),
+                u("php.net/manual"),
+                u("news.ycombinator.com"),
+                u("www.paris.world"),
+                u("https://end.world")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_emails() {
+        let tests = [
+            "john@whatever.net",
+            "jean@sciences-po.fr",
+            "a@univ-paris1.fr",
+            "x@123.fr",
+            "prenom.nom+tag@mail.example.co.uk",
+            "user_name@sub.domain.museum",
+            "someone@company.technology",
+            "o'brien@irish.ie",
+            "élodie.dupré@exemple.fr",
+            "пример@пример.рф",
+            "用户@例子.广告",
+            "0612345678@orange.fr",
+            "jean-pierre@wanadoo.fr",
+            "root@[192.168.0.1]",
+            "!#$%&'*+-/=?^_`{|}~@example.org",
+        ];
+
+        for address in tests {
+            assert_eq!(tokens(address), vec![email(address)]);
+        }
+
+        assert_eq!(
+            tokens("Contact: jean@sciences-po.fr. Or (a@univ-paris1.fr), john.doe@example.com!"),
+            vec![
+                w("Contact"),
+                p(":"),
+                email("jean@sciences-po.fr"),
+                p("."),
+                w("Or"),
+                p("("),
+                email("a@univ-paris1.fr"),
+                p(")"),
+                p(","),
+                email("john.doe@example.com"),
+                p("!")
+            ]
+        );
+
+        assert_eq!(
+            tokens("jean@localhost a@b hello@.fr a..b@x.fr"),
+            vec![
+                w("jean"),
+                m("@localhost"),
+                w("a"),
+                p("@"),
+                w("b"),
+                w("hello"),
+                p("@"),
+                p("."),
+                w("fr"),
+                w("a"),
+                p("."),
+                p("."),
+                email("b@x.fr")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cjk_segmentation() {
+        assert_eq!(
+            tokens("我爱北京天安门！#北京 @微博 http://weibo.com/北京 用户@例子.广告 私は寿司が大好きです😋 iPhone手机2024年"),
+            vec![
+                w("我"),
+                w("爱"),
+                w("北京"),
+                w("天安门"),
+                p("！"),
+                h("#北京"),
+                m("@微博"),
+                u("http://weibo.com/北京"),
+                email("用户@例子.广告"),
+                w("私"),
+                w("は"),
+                w("寿司"),
+                w("が"),
+                w("大好き"),
+                w("です"),
+                e("😋"),
+                w("iPhone"),
+                w("手机"),
+                n("2024"),
+                w("年")
+            ]
+        );
+
+        assert_eq!(
+            tokens("3.5元 价格12.5元 1,000元"),
+            vec![
+                n("3.5"),
+                w("元"),
+                w("价格"),
+                n("12.5"),
+                w("元"),
+                n("1,000"),
+                w("元")
+            ]
+        );
+
+        let offsets = WordTokenizer::new()
+            .tokenize("我爱北京")
+            .map(|token| token.span())
+            .collect::<Vec<_>>();
+
+        assert_eq!(offsets, vec![0..3, 3..6, 6..12]);
+    }
+
+    #[test]
+    fn test_southeast_asian_segmentation() {
+        assert_eq!(
+            tokens(
+                "สวัสดีครับ ผมชอบกินส้มตำ 2 จาน!\u{200b}ขอบคุณ ประเทศไทยมีประชากรประมาณหกสิบหกล้านคน ຂ້ອຍຮັກປະເທດລາວ ខ្ញុំស្រឡាញ់ប្រទេសកម្ពុជា។ မင်္ဂလာပါ"
+            ),
+            vec![
+                w("สวัสดีครับ"),
+                w("ผมชอบกินส้มตำ"),
+                n("2"),
+                w("จาน"),
+                p("!"),
+                w("ขอบคุณ"),
+                w("ประเทศไทยมีประชากรประมาณหกสิบหกล้านคน"),
+                w("ຂ້ອຍຮັກປະເທດລາວ"),
+                w("ខ្ញុំស្រឡាញ់ប្រទេសកម្ពុជា"),
+                p("។"),
+                w("မင်္ဂလာပါ"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invisible_chars() {
+        let text = "\u{feff}Une pho\u{200b}to\u{ad}graphie inter\u{200c}dite\u{200b} à Téhéran: \u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}";
+
+        let tokens = WordTokenizer::new().tokenize(text).collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.text, token.form(), token.span()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Une", "Une", 3..6),
+                ("pho\u{200b}to\u{ad}graphie", "photographie", 7..24),
+                ("inter\u{200c}dite", "interdite", 25..37),
+                ("à", "à", 41..43),
+                ("Téhéran", "Téhéran", 44..53),
+                (":", ":", 53..54),
+                (
+                    "\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}",
+                    "\u{645}\u{6cc}\u{200c}\u{62e}\u{648}\u{627}\u{647}\u{645}",
+                    55..72
+                )
+            ]
+        );
+
+        assert_eq!(tokens[6].normalized, None);
+    }
+
+    #[test]
+    fn test_semitic_mode() {
+        let text = "\u{200f}وَالْكِتَابُ للطلاب، בית\u{200e}הספר והילדים: كتـــاب بيت!";
+
+        assert_eq!(
+            tokens(text),
+            vec![
+                w("وَالْكِتَابُ"),
+                w("للطلاب"),
+                p("،"),
+                w("בית\u{200e}הספר").with_normalized("ביתהספר"),
+                w("והילדים"),
+                p(":"),
+                w("كتـــاب"),
+                w("بيت"),
+                p("!")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .semitic_mode(SemiticMode::Normalize)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens(text),
+            vec![
+                w("وَالْكِتَابُ").with_normalized("والكتاب"),
+                w("للطلاب"),
+                p("،"),
+                w("בית\u{200e}הספר").with_normalized("ביתהספר"),
+                w("והילדים"),
+                p(":"),
+                w("كتـــاب").with_normalized("كتاب"),
+                w("بيت"),
+                p("!")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .semitic_mode(SemiticMode::Split)
+            .build();
+
+        let tokens = tokenizer.tokenize(text).collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.text, token.form(), token.span()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("وَ", "و", 3..7),
+                ("الْ", "ال", 7..13),
+                ("كِتَابُ", "كتاب", 13..27),
+                ("ل", "ل", 28..30),
+                ("ل", "ال", 30..32),
+                ("طلاب", "طلاب", 32..40),
+                ("،", "،", 40..42),
+                ("בית\u{200e}הספר", "ביתהספר", 43..60),
+                ("ו", "ו", 61..63),
+                ("ה", "ה", 63..65),
+                ("ילדים", "ילדים", 65..75),
+                (":", ":", 75..76),
+                ("كتـــاب", "كتاب", 77..91),
+                ("بيت", "بيت", 92..98),
+                ("!", "!", 98..99),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decompound() {
+        let tokenizer = WordTokenizerBuilder::new()
+            .decompound(Decompounder::for_language(Language::German))
+            .build();
+
+        let tokens = tokenizer
+            .tokenize("Das Bundesverfassungsgericht, Karlsruhe.")
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.text, token.form(), token.span()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Das", "Das", 0..3),
+                (
+                    "Bundesverfassungsgericht",
+                    "Bundesverfassungsgericht",
+                    4..28
+                ),
+                ("Bundes", "Bund", 4..10),
+                ("verfassungs", "verfassung", 10..21),
+                ("gericht", "gericht", 21..28),
+                (",", ",", 28..29),
+                ("Karlsruhe", "Karlsruhe", 30..39),
+                (".", ".", 39..40),
+            ]
+        );
+
+        assert_eq!(
+            tokenizer
+                .tokenize_with_char_offsets("Die Straßenbahn fährt")
+                .map(|(token, span)| (token.text, span))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Die", 0..3),
+                ("Straßenbahn", 4..15),
+                ("Straßen", 4..11),
+                ("bahn", 11..15),
+                ("fährt", 16..21)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_penn_treebank() {
+        let tokenizer = WordTokenizerBuilder::new().penn_treebank().build();
+
+        let tests = [
+            (
+                "On a $50,000 mortgage of 30 years at 8 percent, the monthly payment would be $366.88.",
+                "On a $ 50,000 mortgage of 30 years at 8 percent , the monthly payment would be $ 366.88 .",
+            ),
+            (
+                "\"We beat some pretty good teams to get here,\" Slocum said.",
+                "`` We beat some pretty good teams to get here , '' Slocum said .",
+            ),
+            (
+                "Well, we couldn't have this predictable, cliche-ridden, \"Touched by an Angel\" (a show creator John Masius worked on) wanna-be if she didn't.",
+                "Well , we could n't have this predictable , cliche-ridden , `` Touched by an Angel '' -LRB- a show creator John Masius worked on -RRB- wanna-be if she did n't .",
+            ),
+            (
+                "I cannot cannot work under these conditions!",
+                "I can not can not work under these conditions !",
+            ),
+            (
+                "They'll save and invest more.",
+                "They 'll save and invest more .",
+            ),
+            (
+                "hi, my name can't hello,",
+                "hi , my name ca n't hello ,",
+            ),
+            (
+                "It’s the parents' 'car' [sic]... -- “wow”",
+                "It 's the parents ' ` car ' -LSB- sic -RSB- ... -- `` wow ''",
+            ),
+        ];
+
+        for (text, expected) in tests {
+            let tokens = tokenizer.tokenize(text).collect::<Vec<_>>();
+
+            assert_eq!(
+                tokens
+                    .iter()
+                    .map(|token| token.form())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                expected
+            );
+
+            for token in tokens {
+                assert_eq!(&text[token.span()], token.text);
+            }
+        }
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .penn_treebank()
+            .normalize(Normalization::Lowercase)
+            .build();
+
+        assert_eq!(
+            tokenizer
+                .tokenize("(Don't) [sic]")
+                .map(|token| token.form().to_string())
+                .collect::<Vec<_>>(),
+            vec!["-LRB-", "do", "n't", "-RRB-", "-LSB-", "sic", "-RSB-"]
+        );
+    }
+
+    #[test]
+    fn test_elisions() {
+        let text = "L'amour qu'il porte à d'Artagnan et D'Alembert, l'arc-en-ciel de l'@ami.";
+
+        assert_eq!(
+            tokens(text),
+            vec![
+                w("L'"),
+                w("amour"),
+                w("qu'"),
+                w("il"),
+                w("porte"),
+                w("à"),
+                w("d'"),
+                w("Artagnan"),
+                w("et"),
+                w("D'"),
+                w("Alembert"),
+                p(","),
+                w("l'"),
+                w("arc-en-ciel"),
+                w("de"),
+                w("l'"),
+                m("@ami"),
+                p(".")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .elision_mode(ElisionMode::Keep)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens(text),
+            vec![
+                w("L'amour"),
+                w("qu'il"),
+                w("porte"),
+                w("à"),
+                w("d'Artagnan"),
+                w("et"),
+                w("D'Alembert"),
+                p(","),
+                w("l'arc-en-ciel"),
+                w("de"),
+                w("l'"),
+                m("@ami"),
+                p(".")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .elision_mode(ElisionMode::Strip)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokenize(text).take(3).collect::<Vec<_>>(),
+            vec![
+                w("amour").with_offset(2),
+                w("il").with_offset(11),
+                w("porte").with_offset(14)
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokens(text)[6..],
+            vec![
+                w("Alembert"),
+                p(","),
+                w("arc-en-ciel"),
+                w("de"),
+                w("l'"),
+                m("@ami"),
+                p(".")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let custom = |text, label: &str| {
+            let mut token = WordToken::new(text, WordTokenKind::Custom);
+            token.label = Some(label.into());
+            token
+        };
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .custom_pattern("doi", r"10\.\d{4,9}/[^\s]+\b", PatternPriority::First)
+            .custom_pattern(
+                "isbn",
+                r"(?:97[89]-)?\d{1,5}-\d{1,7}-\d{1,7}-[\dX]\b",
+                PatternPriority::First,
+            )
+            .custom_pattern("article", r"[LRD]\. ?\d+(?:-\d+)*", PatternPriority::First)
+            .custom_pattern("gene", r"[A-Z]{2,}\d+[A-Z]?\b", PatternPriority::Last)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens(
+                "Voir doi:10.1000/xyz123, l'ISBN 978-2-07-036822-8 et l'article L. 122-4 sur BRCA1."
+            ),
+            vec![
+                w("Voir"),
+                w("doi"),
+                p(":"),
+                custom("10.1000/xyz123", "doi"),
+                p(","),
+                w("l'"),
+                w("ISBN"),
+                custom("978-2-07-036822-8", "isbn"),
+                w("et"),
+                w("l'"),
+                w("article"),
+                custom("L. 122-4", "article"),
+                w("sur"),
+                custom("BRCA1", "gene"),
+                p(".")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .custom_pattern("year", r"\d{4}\b", PatternPriority::Last)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("En 1789."),
+            vec![w("En"), n("1789"), p(".")]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .custom_pattern(
+                "year",
+                r"\d{4}\b",
+                PatternPriority::Before(WordTokenKind::Number),
+            )
+            .custom_pattern(
+                "number",
+                r"\d+\b",
+                PatternPriority::Before(WordTokenKind::Number),
+            )
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("En 1789, 12 #an1789."),
+            vec![
+                w("En"),
+                custom("1789", "year"),
+                p(","),
+                custom("12", "number"),
+                h("#an1789"),
+                p(".")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .custom_pattern(
+                "chemical",
+                r"(?:[A-Z][a-z]?\d*){2,}\b",
+                PatternPriority::After(WordTokenKind::Url),
+            )
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("H2O et NaCl, pas http://H2O.com"),
+            vec![
+                custom("H2O", "chemical"),
+                w("et"),
+                custom("NaCl", "chemical"),
+                p(","),
+                w("pas"),
+                u("http://H2O.com")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .recognize_dates_and_times()
+            .custom_pattern("version", r"v\d+(?:\.\d+)+\b", PatternPriority::First)
+            .custom_pattern("build", r"\d+\.\d+\.\d+\b", PatternPriority::First)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("v1.2 build 15.03.2024 du 15/03/2024"),
+            vec![
+                custom("v1.2", "version"),
+                w("build"),
+                custom("15.03.2024", "build"),
+                w("du"),
+                WordToken::new("15/03/2024", WordTokenKind::Date)
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .custom_pattern("boundary", r"\b", PatternPriority::First)
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("hello, world"),
+            vec![w("hello"), p(","), w("world")]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_pattern_matching_empty_string() {
+        WordTokenizerBuilder::new().custom_pattern("num", r"\d*", PatternPriority::Last);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_pattern_before_unsupported_kind() {
+        WordTokenizerBuilder::new().custom_pattern(
+            "year",
+            r"\d{4}\b",
+            PatternPriority::Before(WordTokenKind::Date),
+        );
+    }
+
+    #[test]
+    fn test_multiword_expressions() {
+        let text =
+            "Les pommes de terre de New York et de Sciences Po, au fur et à mesure. Et new york ?";
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .multiword_expressions([
+                "New York",
+                "pomme de terre",
+                "pommes de terre",
+                "Sciences Po",
+                "au fur et à mesure",
+                "au fur",
+            ])
+            .stopwords(["de", "et"])
+            .build();
+
+        assert_eq!(
+            tokenizer.tokenize(text).collect::<Vec<_>>(),
+            vec![
+                w("Les").with_offset(0),
+                w("pommes de terre").with_offset(4),
+                w("New York").with_offset(23),
+                w("Sciences Po").with_offset(38),
+                p(",").with_offset(49),
+                w("au fur et à mesure").with_offset(51),
+                p(".").with_offset(70),
+                w("new").with_offset(75),
+                w("york").with_offset(79),
+                p("?").with_offset(84)
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .multiword_expressions(["New York", "New York City"])
+            .multiword_case_insensitive()
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("NEW YORK CITY, new\nyork, New Jersey."),
+            vec![
+                w("NEW YORK CITY"),
+                p(","),
+                w("new\nyork"),
+                p(","),
+                w("New"),
+                w("Jersey"),
+                p(".")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalization() {
+        let forms = |tokenizer: &WordTokenizer, text| {
+            tokenizer
+                .tokenize(text)
+                .map(|token| {
+                    (
+                        token.text.to_string(),
+                        token.normalized.map(|form| form.into_owned()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .normalize(Normalization::Lowercase)
+            .normalize(Normalization::AsciiFold)
+            .normalize(Normalization::ReduceLengthening)
+            .normalize(Normalization::UnifyApostrophes)
+            .build();
+
+        assert_eq!(
+            forms(&tokenizer, "L’Éléphant est cooooool 🐘"),
+            vec![
+                ("L’".to_string(), Some("l'".to_string())),
+                ("Éléphant".to_string(), Some("elephant".to_string())),
+                ("est".to_string(), None),
+                ("cooooool".to_string(), Some("coool".to_string())),
+                ("🐘".to_string(), None)
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .normalize(Normalization::Lowercase)
+            .normalize(Normalization::Stem(Stemmer::Porter))
+            .build();
+
+        assert_eq!(
+            forms(&tokenizer, "Cats are Running #Running"),
+            vec![
+                ("Cats".to_string(), Some("cat".to_string())),
+                ("are".to_string(), Some("ar".to_string())),
+                ("Running".to_string(), Some("run".to_string())),
+                ("#Running".to_string(), Some("#running".to_string()))
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .dehyphenate()
+            .normalize(Normalization::Stem(Stemmer::Carry))
+            .build();
+
+        let tokens = tokenizer
+            .tokenize("Les gouver-\nnements")
+            .collect::<Vec<_>>();
+
+        assert_eq!(tokens[1].text, "gouver-\nnements");
+        assert_eq!(tokens[1].span(), 4..19);
+        assert_eq!(tokens[1].form(), "gouvern");
+    }
+
+    #[test]
+    fn test_apostrophe_names() {
+        let text = "'Abd al-Rahman et 'Ali.";
+
+        assert_eq!(
+            tokens(text),
+            vec![
+                p("'"),
+                w("Abd"),
+                w("al-Rahman"),
+                w("et"),
+                p("'"),
+                w("Ali"),
+                p(".")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new().keep_apostrophe_names().build();
+
+        assert_eq!(
+            tokenizer.tokens(text),
+            vec![w("'Abd"), w("al-Rahman"), w("et"), w("'Ali"), p(".")]
+        );
+
+        assert_eq!(tokenizer.tokens("'Tis 'twas"), vec![w("'Tis"), w("'twas")]);
+
+        assert_eq!(
+            tokenizer.tokens("D'Alembert, D'Après Le Monde"),
+            vec![w("D'Alembert"), p(","), w("D'Après"), w("Le"), w("Monde")]
+        );
+
+        assert_eq!(
+            tokens("D'Après Le Monde"),
+            vec![w("D'"), w("Après"), w("Le"), w("Monde")]
+        );
+    }
+
+    #[test]
+    fn test_graphemes() {
+        assert_eq!(
+            tokens("u\u{332}n\u{332}d\u{332}e\u{332}r\u{332}l\u{332}i\u{332}n\u{332}e\u{332}d\u{332} a\u{304}rrive!"),
+            vec![
+                w("u\u{332}n\u{332}d\u{332}e\u{332}r\u{332}l\u{332}i\u{332}n\u{332}e\u{332}d\u{332}"),
+                w("a\u{304}rrive"),
+                p("!")
+            ]
+        );
+
+        // NFD input
+        assert_eq!(
+            tokens("L'e\u{301}te\u{301} de N'Dja\u{301}mena, cafe\u{301}-the\u{301}!\u{301} #e\u{301}te\u{301}"),
+            vec![
+                w("L'"),
+                w("e\u{301}te\u{301}"),
+                w("de"),
+                w("N'Dja\u{301}mena"),
+                p(","),
+                w("cafe\u{301}-the\u{301}"),
+                p("!\u{301}"),
+                h("#e\u{301}te\u{301}")
+            ]
+        );
+
+        assert_eq!(tokens("नमस्ते दुनिया"), vec![w("नमस्ते"), w("दुनिया")]);
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            tokens("2 2.5 -2 -2.5 2,5 1.2.3"),
+            vec![
+                n("2"),
+                n("2.5"),
+                n("-2"),
+                n("-2.5"),
+                n("2,5"),
+                n("1.2"),
+                p("."),
+                n("3")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_tricky_emojis() {
+        assert_eq!(
+            tokens("'⭐️.🙏⭐️⭐️,⭐️'"),
+            vec![
+                p("'"),
+                e("⭐\u{fe0f}"),
+                p("."),
+                e("🙏"),
+                e("⭐\u{fe0f}"),
+                e("⭐\u{fe0f}"),
+                p(","),
+                e("⭐\u{fe0f}"),
+                p("'")
+            ]
+        );
+
+        assert_eq!(tokens("🐱👪👪👍🏾"), vec![e("🐱"), e("👪"), e("👪"), e("👍🏾")]);
+    }
+
+    #[test]
+    fn test_cashtags() {
+        assert_eq!(
+            tokens("$TSLA up, $BRK.B down. $3.88 $cash $AAPL's $TOOLONG"),
+            vec![
+                c("$TSLA"),
+                w("up"),
+                p(","),
+                c("$BRK.B"),
+                w("down"),
+                p("."),
+                p("$"),
+                n("3.88"),
+                p("$"),
+                w("cash"),
+                c("$AAPL"),
+                w("'s"),
+                p("$"),
+                w("TOOLONG")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inclusive_writing() {
+        assert_eq!(
+            tokens("Les étudiant.e.s, étudiant·es et étudiant(e)s. Les acteur·rice·s et lecteurs/trices sont élu.e.s."),
+            vec![
+                w("Les"),
+                w("étudiant.e.s"),
+                p(","),
+                w("étudiant·es"),
+                w("et"),
+                w("étudiant(e)s"),
+                p("."),
+                w("Les"),
+                w("acteur·rice·s"),
+                w("et"),
+                w("lecteurs/trices"),
+                w("sont"),
+                w("élu.e.s"),
+                p(".")
+            ]
+        );
+
+        assert_eq!(
+            tokens("La vie.est belle. C'est fini.Elle and/or he/she."),
+            vec![
+                w("La"),
+                w("vie"),
+                p("."),
+                w("est"),
+                w("belle"),
+                p("."),
+                w("C'"),
+                w("est"),
+                w("fini"),
+                p("."),
+                w("Elle"),
+                w("and"),
+                p("/"),
+                w("or"),
+                w("he"),
+                p("/"),
+                w("she"),
+                p(".")
+            ]
+        );
+
+        let tests = [
+            ("étudiant.e.s", "étudiants", "étudiantes"),
+            ("étudiant.es", "étudiants", "étudiantes"),
+            ("étudiant(e)s", "étudiants", "étudiantes"),
+            ("étudiant(e)(s)", "étudiants", "étudiantes"),
+            ("élu.e", "élu", "élue"),
+            ("acteur·rice·s", "acteurs", "actrices"),
+            ("lecteurs/trices", "lecteurs", "lectrices"),
+            ("danseur·euse", "danseur", "danseuse"),
+            ("boulanger·ère·s", "boulangers", "boulangères"),
+            ("citoyen·ne·s", "citoyens", "citoyennes"),
+            ("heureux·se", "heureux", "heureuse"),
+            ("sportif·ve·s", "sportifs", "sportives"),
+            ("citoyen.ne.s", "citoyens", "citoyennes"),
+            ("réel.le", "réel", "réelle"),
+            ("muet.te.s", "muets", "muettes"),
+            ("heureux.se", "heureux", "heureuse"),
+            ("acteur.ice.s", "acteurs", "actrices"),
+            ("directeur·ice", "directeur", "directrice"),
+            ("candidat(e)s", "candidats", "candidates"),
+        ];
+
+        for (form, masculine, feminine) in tests {
+            assert_eq!(
+                expand_inclusive_form(form),
+                Some((masculine.to_string(), feminine.to_string()))
+            );
+        }
+
+        assert_eq!(expand_inclusive_form("étudiant"), None);
+        assert_eq!(expand_inclusive_form("étudiant.e.s."), None);
+
+        for form in ["Paris(e)", "fichier.e", "end.e", "fini.e-mail"] {
+            assert_eq!(expand_inclusive_form(form), None);
         }
+
+        assert_eq!(
+            tokens("C'est fini.e-mail, Paris(e) fichier.e the end.e"),
+            vec![
+                w("C'"),
+                w("est"),
+                w("fini"),
+                p("."),
+                w("e-mail"),
+                p(","),
+                w("Paris"),
+                p("("),
+                w("e"),
+                p(")"),
+                w("fichier"),
+                p("."),
+                w("e"),
+                w("the"),
+                w("end"),
+                p("."),
+                w("e")
+            ]
+        );
+
+        assert_eq!(
+            tokens("Il est venu.le lendemain, pas.te voilà. Il mange.se tait à 20 ans.le reste, etc.ne pas oublier. Etc.ne"),
+            vec![
+                w("Il"),
+                w("est"),
+                w("venu"),
+                p("."),
+                w("le"),
+                w("lendemain"),
+                p(","),
+                w("pas"),
+                p("."),
+                w("te"),
+                w("voilà"),
+                p("."),
+                w("Il"),
+                w("mange"),
+                p("."),
+                w("se"),
+                w("tait"),
+                w("à"),
+                n("20"),
+                w("ans"),
+                p("."),
+                w("le"),
+                w("reste"),
+                p(","),
+                w("etc."),
+                w("ne"),
+                w("pas"),
+                w("oublier"),
+                p("."),
+                w("Etc."),
+                w("ne")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new().expand_inclusive_forms().build();
+
+        assert_eq!(
+            tokenizer
+                .tokenize("Les étudiant.e.s!")
+                .map(|token| (token.form().to_string(), token.span()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Les".to_string(), 0..3),
+                ("étudiants".to_string(), 4..17),
+                ("étudiantes".to_string(), 4..17),
+                ("!".to_string(), 17..18)
+            ]
+        );
+
+        assert_eq!(
+            tokenizer
+                .tokenize_with_char_offsets("les étudiant.e.s sont là")
+                .map(|(token, span)| (token.form().to_string(), span))
+                .collect::<Vec<_>>(),
+            vec![
+                ("les".to_string(), 0..3),
+                ("étudiants".to_string(), 4..16),
+                ("étudiantes".to_string(), 4..16),
+                ("sont".to_string(), 17..21),
+                ("là".to_string(), 22..24)
+            ]
+        );
     }
 
     #[test]
-    fn test_numbers() {
+    fn test_dehyphenation() {
+        let text = "Le gouver-\nnement et le porte-\r\n  monnaie de Jean-\nPierre.";
+
         assert_eq!(
-            tokens("2 2.5 -2 -2.5 2,5 1.2.3"),
+            tokens(text),
             vec![
-                n("2"),
-                n("2.5"),
-                n("-2"),
-                n("-2.5"),
-                n("2,5"),
-                n("1.2"),
+                w("Le"),
+                w("gouver"),
+                p("-"),
+                w("nement"),
+                w("et"),
+                w("le"),
+                w("porte"),
+                p("-"),
+                w("monnaie"),
+                w("de"),
+                w("Jean"),
+                p("-"),
+                w("Pierre"),
+                p(".")
+            ]
+        );
+
+        let forms = |tokenizer: &WordTokenizer| {
+            tokenizer
+                .tokenize(text)
+                .map(|token| (token.form().to_string(), token.span()))
+                .collect::<Vec<_>>()
+        };
+
+        let tokenizer = WordTokenizerBuilder::new().dehyphenate().build();
+
+        assert_eq!(
+            forms(&tokenizer),
+            vec![
+                ("Le".to_string(), 0..2),
+                ("gouvernement".to_string(), 3..17),
+                ("et".to_string(), 18..20),
+                ("le".to_string(), 21..23),
+                ("portemonnaie".to_string(), 24..41),
+                ("de".to_string(), 42..44),
+                ("Jean-Pierre".to_string(), 45..57),
+                (".".to_string(), 57..58)
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .dehyphenate_with(|word| word == "porte-monnaie")
+            .build();
+
+        assert_eq!(forms(&tokenizer)[4], ("porte-monnaie".to_string(), 24..41));
+
+        let tokenizer = WordTokenizerBuilder::new().dehyphenate().build();
+
+        assert_eq!(
+            tokenizer
+                .tokenize("un porte-\n\n\nmonnaie")
+                .map(|token| token.text)
+                .collect::<Vec<_>>(),
+            vec!["un", "porte", "-", "monnaie"]
+        );
+
+        assert_eq!(
+            tokenizer
+                .tokenize("ma mother-in-\nlaw")
+                .map(|token| token.form().to_string())
+                .collect::<Vec<_>>(),
+            vec!["ma", "mother-in-law"]
+        );
+    }
+
+    #[test]
+    fn test_platforms() {
+        let text =
+            "u/someone /u/some-one r/france @user@mastodon.social @handle.bsky.social. @yomgui";
+
+        assert_eq!(
+            tokens(text),
+            vec![
+                w("u"),
+                p("/"),
+                w("someone"),
+                p("/"),
+                w("u"),
+                p("/"),
+                w("some-one"),
+                w("r"),
+                p("/"),
+                w("france"),
+                m("@user"),
+                m("@mastodon"),
                 p("."),
-                n("3")
+                w("social"),
+                m("@handle"),
+                p("."),
+                w("bsky"),
+                p("."),
+                w("social"),
+                p("."),
+                m("@yomgui")
             ]
-        )
+        );
+
+        let community = |t| WordToken::new(t, WordTokenKind::Community);
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .platforms([Platform::Reddit, Platform::Mastodon, Platform::Bluesky])
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens(text),
+            vec![
+                m("u/someone"),
+                m("/u/some-one"),
+                community("r/france"),
+                m("@user@mastodon.social"),
+                m("@handle.bsky.social"),
+                p("."),
+                m("@yomgui")
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .platforms([Platform::Reddit])
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("r/a u/ab @user@mastodon.social"),
+            vec![
+                w("r"),
+                p("/"),
+                w("a"),
+                w("u"),
+                p("/"),
+                w("ab"),
+                m("@user"),
+                m("@mastodon"),
+                p("."),
+                w("social")
+            ]
+        );
     }
 
     #[test]
-    fn test_tricky_emojis() {
+    fn test_dates_and_times() {
+        let text = "Le 2024-03-15 ou le 15/03/2024 à 14h30, 03/15/24 at 10:45pm (2024-03-15T14:30:00Z) 15.03.2024 14:30:15 10pm 10 a.m. 14h 2024/03/15 pendant 1h30min ou 45min PT1H30M.";
+
+        let date = |t| WordToken::new(t, WordTokenKind::Date);
+        let time = |t| WordToken::new(t, WordTokenKind::Time);
+        let duration = |t| WordToken::new(t, WordTokenKind::Duration);
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .recognize_dates_and_times()
+            .build();
+
         assert_eq!(
-            tokens("'⭐️.🙏⭐️⭐️,⭐️'"),
+            tokenizer.tokens(text),
             vec![
-                p("'"),
-                e("⭐\u{fe0f}"),
+                w("Le"),
+                date("2024-03-15"),
+                w("ou"),
+                w("le"),
+                date("15/03/2024"),
+                w("à"),
+                time("14h30"),
+                p(","),
+                date("03/15/24"),
+                w("at"),
+                time("10:45pm"),
+                p("("),
+                date("2024-03-15T14:30:00Z"),
+                p(")"),
+                date("15.03.2024"),
+                time("14:30:15"),
+                time("10pm"),
+                n("10"),
+                w("a"),
                 p("."),
-                e("🙏"),
-                e("⭐\u{fe0f}"),
-                e("⭐\u{fe0f}"),
+                w("m."),
+                time("14h"),
+                date("2024/03/15"),
+                w("pendant"),
+                duration("1h30min"),
+                w("ou"),
+                duration("45min"),
+                duration("PT1H30M"),
+                p(".")
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokens("1.2.3 99/99/2024 25h 4.5 3h30min12"),
+            vec![
+                n("1.2"),
+                p("."),
+                n("3"),
+                n("99"),
+                p("/"),
+                n("99"),
+                p("/"),
+                n("2024"),
+                w("25h"),
+                n("4.5"),
+                w("3h30min12")
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokens("31/31/2024 12.5.10 12.30.45 12.30.2024 31-12-99"),
+            vec![
+                n("31"),
+                p("/"),
+                n("31"),
+                p("/"),
+                n("2024"),
+                n("12.5"),
+                p("."),
+                n("10"),
+                n("12.30"),
+                p("."),
+                n("45"),
+                date("12.30.2024"),
+                date("31-12-99")
+            ]
+        );
+
+        // Default behaviour is kept
+        assert_eq!(
+            tokens("2024-03-15 14h30 10:45"),
+            vec![w("2024-03-15"), w("14h30"), n("10"), p(":"), n("45")]
+        );
+    }
+
+    #[test]
+    fn test_quantities() {
+        let money = |t| WordToken::new(t, WordTokenKind::Money);
+        let percent = |t| WordToken::new(t, WordTokenKind::Percent);
+        let ordinal = |t| WordToken::new(t, WordTokenKind::Ordinal);
+
+        let tokenizer = WordTokenizerBuilder::new().recognize_quantities().build();
+
+        assert_eq!(
+            tokenizer.tokens("Good muffins cost $3.88, 12 000 € or 12\u{202f}000\u{a0}€ and €4 Millions, 4M€, 1,250.50 USD."),
+            vec![
+                w("Good"),
+                w("muffins"),
+                w("cost"),
+                money("$3.88"),
                 p(","),
-                e("⭐\u{fe0f}"),
-                p("'")
+                money("12 000 €"),
+                w("or"),
+                money("12\u{202f}000\u{a0}€"),
+                w("and"),
+                money("€4"),
+                w("Millions"),
+                p(","),
+                money("4M€"),
+                p(","),
+                money("1,250.50 USD"),
+                p(".")
             ]
         );
 
-        assert_eq!(tokens("🐱👪👪👍🏾"), vec![e("🐱"), e("👪"), e("👪"), e("👍🏾")]);
+        assert_eq!(
+            tokenizer.tokens("10 CADRES, 3 AUDITEURS et 5 EUROS mais 5 EUR."),
+            vec![
+                n("10"),
+                w("CADRES"),
+                p(","),
+                n("3"),
+                w("AUDITEURS"),
+                w("et"),
+                n("5"),
+                w("EUROS"),
+                w("mais"),
+                money("5 EUR"),
+                p(".")
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokens("50% des 12,5 % et -3\u{a0}% en 2019 300 €"),
+            vec![
+                percent("50%"),
+                w("des"),
+                percent("12,5 %"),
+                w("et"),
+                percent("-3\u{a0}%"),
+                w("en"),
+                n("2019"),
+                money("300 €")
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokens(
+                "7e 1er 1re 1ère 7eme 7ème 7th 1st 3rd 2nd 2nde 11º XIXe siècle Le 7even 2.5"
+            ),
+            vec![
+                ordinal("7e"),
+                ordinal("1er"),
+                ordinal("1re"),
+                ordinal("1ère"),
+                ordinal("7eme"),
+                ordinal("7ème"),
+                ordinal("7th"),
+                ordinal("1st"),
+                ordinal("3rd"),
+                ordinal("2nd"),
+                ordinal("2nde"),
+                ordinal("11º"),
+                ordinal("XIXe"),
+                w("siècle"),
+                w("Le"),
+                w("7even"),
+                n("2.5")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_smileys() {
+        assert_eq!(
+            tokens("Coucou :-) ;p :'( =D >:[ :/ :3 <3 </3 ^^ ^_^ -_- xD XDDD"),
+            vec![
+                w("Coucou"),
+                s(":-)"),
+                s(";p"),
+                s(":'("),
+                s("=D"),
+                s(">:["),
+                s(":/"),
+                s(":3"),
+                s("<3"),
+                s("</3"),
+                s("^^"),
+                s("^_^"),
+                s("-_-"),
+                s("xD"),
+                s("XDDD")
+            ]
+        );
+
+        assert_eq!(
+            tokens("Trop bien:) (vraiment :()"),
+            vec![
+                w("Trop"),
+                w("bien"),
+                s(":)"),
+                p("("),
+                w("vraiment"),
+                p(":"),
+                p("("),
+                p(")")
+            ]
+        );
+
+        assert_eq!(
+            tokens("(voir :( triste)\n:("),
+            vec![
+                p("("),
+                w("voir"),
+                p(":"),
+                p("("),
+                w("triste"),
+                p(")"),
+                s(":(")
+            ]
+        );
+
+        assert_eq!(
+            tokens("ratio 2:3, n=3 samples, i=3; f(x)=D(x), see a;p Voici:D (:D) :D"),
+            vec![
+                w("ratio"),
+                n("2"),
+                p(":"),
+                n("3"),
+                p(","),
+                w("n"),
+                p("="),
+                n("3"),
+                w("samples"),
+                p(","),
+                w("i"),
+                p("="),
+                n("3"),
+                p(";"),
+                w("f"),
+                p("("),
+                w("x"),
+                p(")"),
+                p("="),
+                w("D"),
+                p("("),
+                w("x"),
+                p(")"),
+                p(","),
+                w("see"),
+                w("a"),
+                p(";"),
+                w("p"),
+                w("Voici"),
+                p(":"),
+                w("D"),
+                p("("),
+                s(":D"),
+                p(")"),
+                s(":D")
+            ]
+        );
+
+        assert_eq!(
+            tokens("Two cases:(a) and (b:) 8) ok"),
+            vec![
+                w("Two"),
+                w("cases"),
+                p(":"),
+                p("("),
+                w("a"),
+                p(")"),
+                w("and"),
+                p("("),
+                w("b"),
+                s(":)"),
+                n("8"),
+                p(")"),
+                w("ok")
+            ]
+        );
+
+        assert_eq!(
+            tokens("Meeting at 10:30 :Done <30"),
+            vec![
+                w("Meeting"),
+                w("at"),
+                n("10"),
+                p(":"),
+                n("30"),
+                p(":"),
+                w("Done"),
+                p("<"),
+                n("30")
+            ]
+        );
     }
 
     #[test]
@@ -1412,6 +4618,8 @@ mod tests {
     #[test]
     fn test_word_token_kind() {
         assert_eq!(WordTokenKind::Email.as_str(), "email");
+        assert_eq!(WordTokenKind::Smiley.as_str(), "smiley");
+        assert_eq!("smiley".parse::<WordTokenKind>(), Ok(WordTokenKind::Smiley));
         assert_eq!("url".parse::<WordTokenKind>(), Ok(WordTokenKind::Url));
 
         assert_eq!(
@@ -1425,14 +4633,14 @@ mod tests {
         where
             'a: 'b,
         {
-            self.tokenize(text).collect()
+            self.tokenize(text).map(strip_offset).collect()
         }
 
         fn simple_tokens<'a, 'b>(&'a self, text: &'b str) -> Vec<WordToken<'b>>
         where
             'a: 'b,
         {
-            self.simple_tokenize(text).collect()
+            self.simple_tokenize(text).map(strip_offset).collect()
         }
     }
 
@@ -1445,6 +4653,28 @@ mod tests {
         assert_eq!(tokenizer.tokens("le chat"), vec![w("le"), w("chat")]);
     }
 
+    #[test]
+    fn test_stop_patterns() {
+        let tokenizer = WordTokenizerBuilder::new()
+            .stop_pattern("\\d+h")
+            .stop_prefix("Anti")
+            .stopwords(["le"])
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("Le ANTIVOL de 12h est antique, 12h30 contre l'anti."),
+            vec![
+                w("de"),
+                w("est"),
+                p(","),
+                w("12h30"),
+                w("contre"),
+                w("l'"),
+                p(".")
+            ]
+        );
+    }
+
     #[test]
     fn test_stopwords() {
         let mut builder = WordTokenizerBuilder::new();
@@ -1469,6 +4699,22 @@ mod tests {
             tokenizer.tokens("le chat mange la souris"),
             vec![w("le"), w("mange"), w("la")]
         );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .stopwords(["Œuvre", "l’"])
+            .build();
+
+        assert_eq!(tokenizer.tokens("L'œuvre ŒUVRE œuvrer"), vec![w("œuvrer")]);
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .language_stopwords(Language::French)
+            .stopwords(["souris"])
+            .build();
+
+        assert_eq!(
+            tokenizer.tokens("Le chat mange la souris qu'il a vue."),
+            vec![w("chat"), w("mange"), w("vue"), p(".")]
+        );
     }
 
     #[test]
@@ -1509,6 +4755,37 @@ mod tests {
             tokenizer.tokens("le chat oufehfhhhhhhhh"),
             vec![w("le"), w("chat")]
         );
+
+        let text = "Die Angstschweißausbrüche sind zzz";
+        let junk = |tokenizer: &WordTokenizer| {
+            tokenizer
+                .junk_tokens(text)
+                .map(|(token, reason)| (token.text, reason))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            junk(&tokenizer),
+            vec![
+                (
+                    "Angstschweißausbrüche",
+                    JunkReason::TooManyConsecutiveConsonants
+                ),
+                ("zzz", JunkReason::NoVowels)
+            ]
+        );
+
+        let tokenizer = WordTokenizerBuilder::new()
+            .filter_junk_with(JunkDetector::for_language(Language::German))
+            .build();
+
+        assert_eq!(junk(&tokenizer), vec![("zzz", JunkReason::NoVowels)]);
+        assert_eq!(
+            tokenizer.tokens(text),
+            vec![w("Die"), w("Angstschweißausbrüche"), w("sind")]
+        );
+
+        assert!(WordTokenizer::new().junk_tokens(text).next().is_none());
     }
 
     #[test]
@@ -1523,31 +4800,9 @@ mod tests {
 
     #[test]
     fn test_starts_with_vowel() {
-        assert_eq!(starts_with_vowel("à"), true);
-        assert_eq!(starts_with_vowel("A"), true);
-        assert_eq!(starts_with_vowel("f"), false);
-        assert_eq!(starts_with_vowel("F"), false);
-    }
-
-    #[test]
-    fn test_is_junk() {
-        let tests = [
-            ("aeaeaea", true),
-            ("aeaeae", false),
-            ("cbcbcbcb", true),
-            ("paltoquet", false),
-            ("azazazazazazazazazazazazazazazazazazaz", true),
-            ("d", true),
-            ("d'", false),
-            ("créé", false),
-            ("créée", false),
-            ("creee", false),
-            ("creeee", true),
-            ("TATA", false),
-        ];
-
-        for (string, expected) in tests {
-            assert_eq!(is_junk(string), expected);
-        }
+        assert!(starts_with_vowel("à"));
+        assert!(starts_with_vowel("A"));
+        assert!(!starts_with_vowel("f"));
+        assert!(!starts_with_vowel("F"));
     }
 }
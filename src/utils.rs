@@ -1,5 +1,15 @@
 use std::borrow::Cow;
 
+use lazy_static::lazy_static;
+use regex_automata::meta::Regex;
+
+lazy_static! {
+    // NOTE: the left part may itself be a compound, e.g. "mother-in-\nlaw"
+    pub(crate) static ref HYPHENATION_REGEX: Regex =
+        Regex::new("((?:\\p{Alpha}[\\p{Alpha}\\p{M}]*[\\-\u{2010}])*\\p{Alpha}[\\p{Alpha}\\p{M}]*)[\\-\u{ad}\u{2010}][\\t ]*(?:\\r\\n|\\n|\\r)[\\t ]*(\\p{Alpha}[\\p{Alpha}\\p{M}]*)\\b")
+            .unwrap();
+}
+
 pub fn reduce_lengthening(string: &str) -> String {
     let mut output: String = String::with_capacity(string.len());
 
@@ -29,25 +39,20 @@ pub fn reduce_lengthening(string: &str) -> String {
     output
 }
 
-pub fn squeeze(string: &str) -> Cow<str> {
+pub fn squeeze(string: &str) -> Cow<'_, str> {
     let mut output = String::new();
 
     let mut last_char: Option<char> = None;
 
     for (i, c) in string.char_indices() {
         match last_char {
-            Some(last) => {
-                if c == last {
-                    if output.is_empty() {
-                        output.reserve(string.len().saturating_sub(1));
-                        output.push_str(&string[..i]);
-                    }
-                } else {
-                    output.push(c);
-                    last_char = Some(c);
+            Some(last) if c == last => {
+                if output.is_empty() {
+                    output.reserve(string.len().saturating_sub(1));
+                    output.push_str(&string[..i]);
                 }
             }
-            None => {
+            _ => {
                 output.push(c);
                 last_char = Some(c);
             }
@@ -61,6 +66,67 @@ pub fn squeeze(string: &str) -> Cow<str> {
     }
 }
 
+// Joins the parts of a word hyphenated across a line break. Continuations
+// starting with an uppercase letter, e.g. "Jean-\nPierre", parts of a longer
+// compound, e.g. "mother-in-\nlaw", as well as compounds accepted by the
+// lexicon hook, e.g. "porte-\nmonnaie", keep their hyphen.
+pub(crate) fn join_hyphenated<F>(left: &str, right: &str, is_compound: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut joined = String::with_capacity(left.len() + right.len() + 1);
+    joined.push_str(left);
+    joined.push('-');
+    joined.push_str(right);
+
+    if right.starts_with(char::is_uppercase)
+        || left.contains(['-', '\u{2010}'])
+        || is_compound(&joined)
+    {
+        return joined;
+    }
+
+    joined.remove(left.len());
+    joined
+}
+
+/// Rejoins words hyphenated across line breaks, as commonly found in OCR'd or
+/// PDF-extracted text, e.g. "gouver-\nnement" -> "gouvernement".
+pub fn dehyphenate(string: &str) -> Cow<'_, str> {
+    dehyphenate_with(string, |_| false)
+}
+
+/// Same as [`dehyphenate`] but relying on the given lexicon hook to decide
+/// whether a hyphenated form, e.g. "porte-monnaie", is a true compound whose
+/// hyphen should be kept.
+pub fn dehyphenate_with<F>(string: &str, is_compound: F) -> Cow<'_, str>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut output = String::new();
+    let mut last_end: usize = 0;
+
+    for caps in HYPHENATION_REGEX.captures_iter(string) {
+        let m = caps.get_match().unwrap();
+
+        let left = &string[caps.get_group(1).unwrap().range()];
+        let right = &string[caps.get_group(2).unwrap().range()];
+
+        output.push_str(&string[last_end..m.start()]);
+        output.push_str(&join_hyphenated(left, right, &is_compound));
+
+        last_end = m.end();
+    }
+
+    if last_end == 0 {
+        return Cow::Borrowed(string);
+    }
+
+    output.push_str(&string[last_end..]);
+
+    Cow::Owned(output)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +161,30 @@ mod tests {
             Cow::<str>::Owned("whatever".to_string())
         );
     }
+
+    #[test]
+    fn test_dehyphenate() {
+        assert_eq!(dehyphenate("le chat"), Cow::Borrowed("le chat"));
+        assert_eq!(
+            dehyphenate("Le gouver-\nnement a dé-  \r\n  cidé, Jean-\nPierre."),
+            "Le gouvernement a décidé, Jean-Pierre."
+        );
+        assert_eq!(dehyphenate("un porte-\nmonnaie"), "un portemonnaie");
+        assert_eq!(
+            dehyphenate_with("un porte-\nmonnaie et un gouver-\nnement", |word| {
+                word == "porte-monnaie"
+            }),
+            "un porte-monnaie et un gouvernement"
+        );
+        assert_eq!(
+            dehyphenate("mother-in-law\nand -\nthat"),
+            Cow::Borrowed("mother-in-law\nand -\nthat")
+        );
+        assert_eq!(
+            dehyphenate("un porte-\n\n\nmonnaie"),
+            Cow::Borrowed("un porte-\n\n\nmonnaie")
+        );
+        assert_eq!(dehyphenate("Ame\u{301}-\nlie"), "Ame\u{301}lie");
+        assert_eq!(dehyphenate("my mother-in-\nlaw"), "my mother-in-law");
+    }
 }